@@ -0,0 +1,645 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+include!("src/data/rules.rs");
+
+#[derive(Deserialize)]
+struct DeployMode {
+    languages: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Provenance {
+    source: String,
+    last_verified: String,
+}
+
+#[derive(Deserialize)]
+struct Ecosystem {
+    id: String,
+    name: String,
+    short: String,
+    languages: Vec<String>,
+    vm: String,
+    transaction_model: String,
+    evm_compatibility: String,
+    deployment_options: Vec<String>,
+    chain_layer: String,
+    l2_maturity: u8,
+    consensus: String,
+    tooling_maturity: u8,
+    tooling: Vec<String>,
+    doc_quality: u8,
+    ecosystem_funding: u8,
+    #[serde(default)]
+    group: String,
+    position: Option<[f64; 2]>,
+    deploy_modes: Option<HashMap<String, DeployMode>>,
+    #[serde(default)]
+    provenance: BTreeMap<String, Provenance>,
+}
+
+#[derive(Deserialize)]
+struct EcosystemFile {
+    ecosystem: Vec<Ecosystem>,
+}
+
+#[derive(Deserialize)]
+struct PairOverride {
+    source: String,
+    dest: String,
+    src_mode: Option<String>,
+    dst_mode: Option<String>,
+    #[serde(default)]
+    adjust: HashMap<String, f64>,
+    #[serde(default)]
+    positives: Vec<String>,
+    #[serde(default)]
+    challenges: Vec<String>,
+    justification: String,
+    source_url: String,
+}
+
+#[derive(Deserialize)]
+struct PairOverrideFile {
+    #[serde(rename = "override", default)]
+    overrides: Vec<PairOverride>,
+}
+
+fn quote(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+fn str_vec(v: &[String]) -> String {
+    let items: Vec<String> = v.iter().map(|s| format!("{}.into()", quote(s))).collect();
+    format!("vec![{}]", items.join(", "))
+}
+
+#[derive(Deserialize)]
+struct MitigationEntry {
+    kind: String,
+    dest: Option<String>,
+    #[serde(default)]
+    src_evm: Vec<String>,
+    unless_dst_mode: Option<String>,
+    category: String,
+    text: String,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MitigationFile {
+    #[serde(rename = "mitigation", default)]
+    mitigations: Vec<MitigationEntry>,
+}
+
+#[derive(Deserialize)]
+struct EffortRange {
+    low: f64,
+    high: f64,
+    docs_spread: f64,
+}
+
+#[derive(Deserialize)]
+struct EffortPhase {
+    base: f64,
+    per_dev: f64,
+    per_kloc: f64,
+    per_contract: f64,
+    floor: f64,
+    coverage_penalty: f64,
+    dimensions: HashMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+struct EffortFile {
+    range: EffortRange,
+    phase: HashMap<String, EffortPhase>,
+}
+
+#[derive(Deserialize)]
+struct ToolCategory {
+    id: String,
+    name: String,
+    tools: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ToolCategoryFile {
+    category: Vec<ToolCategory>,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    version: String,
+    date: String,
+    changes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ChangelogFile {
+    release: Vec<Release>,
+}
+
+fn str_slice(v: &[String]) -> String {
+    let items: Vec<String> = v.iter().map(|s| quote(s)).collect();
+    format!("&[{}]", items.join(", "))
+}
+
+fn opt_str(v: &Option<String>) -> String {
+    match v {
+        Some(s) => format!("Some({})", quote(s)),
+        None => "None".to_string(),
+    }
+}
+
+/// Map a dimension key from `pair_overrides.toml` to its `Dimension` variant.
+fn dimension_variant(key: &str) -> Option<&'static str> {
+    match key {
+        "language" => Some("Language"),
+        "vm" => Some("Vm"),
+        "tx_model" => Some("TxModel"),
+        "evm_compat" => Some("EvmCompat"),
+        "deploy" => Some("Deploy"),
+        "tooling" => Some("Tooling"),
+        "docs" => Some("Docs"),
+        "l2" => Some("L2"),
+        "funding" => Some("Funding"),
+        _ => None,
+    }
+}
+
+/// Map a challenge kind key from `mitigations.toml` to its `FindingKind` variant.
+fn finding_kind_variant(key: &str) -> Option<&'static str> {
+    match key {
+        "language_change" => Some("LanguageChange"),
+        "related_language_change" => Some("RelatedLanguageChange"),
+        "vm_change" => Some("VmChange"),
+        "tx_model_change" => Some("TxModelChange"),
+        "leaving_evm" => Some("LeavingEvm"),
+        "entering_evm" => Some("EnteringEvm"),
+        "deploy_model_change" => Some("DeployModelChange"),
+        "immature_tooling" => Some("ImmatureTooling"),
+        "limited_docs" => Some("LimitedDocs"),
+        "l2_gap" => Some("L2Gap"),
+        "limited_funding" => Some("LimitedFunding"),
+        _ => None,
+    }
+}
+
+fn mitigation_category_variant(key: &str) -> Option<&'static str> {
+    match key {
+        "compatibility" => Some("Compatibility"),
+        "transpiler" => Some("Transpiler"),
+        "framework" => Some("Framework"),
+        "learning" => Some("Learning"),
+        _ => None,
+    }
+}
+
+fn generate_mitigations(ecosystems: &[Ecosystem], out_dir: &str) {
+    let raw = fs::read_to_string("data/mitigations.toml").expect("read mitigations.toml");
+    let file: MitigationFile = toml::from_str(&raw).expect("parse mitigations.toml");
+
+    let mut code = String::from("pub static MITIGATIONS: &[MitigationEntry] = &[\n");
+
+    for m in &file.mitigations {
+        let kind = finding_kind_variant(&m.kind)
+            .unwrap_or_else(|| panic!("mitigations.toml: unknown challenge kind {:?}", m.kind));
+        let category = mitigation_category_variant(&m.category)
+            .unwrap_or_else(|| panic!("mitigations.toml: unknown category {:?}", m.category));
+        let dest = m.dest.as_ref().map(|dest| {
+            ecosystems
+                .iter()
+                .find(|e| &e.id == dest)
+                .unwrap_or_else(|| panic!("mitigations.toml: unknown ecosystem id {:?}", dest))
+        });
+        for evm in &m.src_evm {
+            assert!(
+                EVM_COMPATIBILITY.contains(&evm.as_str()),
+                "mitigations.toml: src_evm {:?} must be one of {}",
+                evm,
+                EVM_COMPATIBILITY.join(", ")
+            );
+        }
+        if let Some(mode) = &m.unless_dst_mode {
+            let has_mode = dest.is_some_and(|d| {
+                d.deployment_options.contains(mode)
+                    || d.deploy_modes
+                        .as_ref()
+                        .is_some_and(|modes| modes.contains_key(mode))
+            });
+            assert!(
+                has_mode,
+                "mitigations.toml: unless_dst_mode {:?} isn't a deploy mode of {:?}",
+                mode, m.dest
+            );
+        }
+
+        let src_evm: Vec<String> = m.src_evm.iter().map(|e| quote(e)).collect();
+        code.push_str("    MitigationEntry {\n");
+        code.push_str(&format!("        kind: FindingKind::{},\n", kind));
+        code.push_str(&format!("        dest: {},\n", opt_str(&m.dest)));
+        code.push_str(&format!("        src_evm: &[{}],\n", src_evm.join(", ")));
+        code.push_str(&format!(
+            "        unless_dst_mode: {},\n",
+            opt_str(&m.unless_dst_mode)
+        ));
+        code.push_str("        mitigation: Mitigation {\n");
+        code.push_str(&format!(
+            "            category: MitigationCategory::{},\n",
+            category
+        ));
+        code.push_str(&format!("            text: {},\n", quote(&m.text)));
+        code.push_str(&format!("            url: {},\n", opt_str(&m.url)));
+        code.push_str("        },\n");
+        code.push_str("    },\n");
+    }
+
+    code.push_str("];\n");
+    let dest = Path::new(out_dir).join("mitigations_generated.rs");
+    fs::write(&dest, code).expect("write generated mitigations file");
+}
+
+/// Phases of the effort estimate, in display order, with their `Phase` variants.
+const EFFORT_PHASES: [(&str, &str); 5] = [
+    ("learning", "Learning"),
+    ("rewrite", "Rewrite"),
+    ("tooling", "Tooling"),
+    ("audit", "Audit"),
+    ("deployment", "Deployment"),
+];
+
+fn generate_effort(out_dir: &str) {
+    let raw = fs::read_to_string("data/effort.toml").expect("read effort.toml");
+    let file: EffortFile = toml::from_str(&raw).expect("parse effort.toml");
+
+    for key in file.phase.keys() {
+        assert!(
+            EFFORT_PHASES.iter().any(|(k, _)| k == key),
+            "effort.toml: unknown phase {:?}",
+            key
+        );
+    }
+
+    let mut code = format!(
+        "pub static EFFORT_RANGE: RangeCoefficients = RangeCoefficients {{ low: {:?}, high: {:?}, docs_spread: {:?} }};\n\n",
+        file.range.low, file.range.high, file.range.docs_spread
+    );
+    code.push_str("pub static PHASE_COEFFICIENTS: [PhaseCoefficients; 5] = [\n");
+
+    for (key, variant) in EFFORT_PHASES {
+        let phase = file
+            .phase
+            .get(key)
+            .unwrap_or_else(|| panic!("effort.toml: missing phase {:?}", key));
+
+        let mut dims: Vec<&String> = phase.dimensions.keys().collect();
+        dims.sort();
+        let dimensions: Vec<String> = dims
+            .into_iter()
+            .map(|k| {
+                let dim = dimension_variant(k)
+                    .unwrap_or_else(|| panic!("effort.toml: unknown dimension {:?}", k));
+                format!("(Dimension::{}, {:?})", dim, phase.dimensions[k])
+            })
+            .collect();
+
+        code.push_str("    PhaseCoefficients {\n");
+        code.push_str(&format!("        phase: Phase::{},\n", variant));
+        code.push_str(&format!("        base: {:?},\n", phase.base));
+        code.push_str(&format!("        per_dev: {:?},\n", phase.per_dev));
+        code.push_str(&format!("        per_kloc: {:?},\n", phase.per_kloc));
+        code.push_str(&format!("        per_contract: {:?},\n", phase.per_contract));
+        code.push_str(&format!("        floor: {:?},\n", phase.floor));
+        code.push_str(&format!(
+            "        coverage_penalty: {:?},\n",
+            phase.coverage_penalty
+        ));
+        code.push_str(&format!("        dimensions: &[{}],\n", dimensions.join(", ")));
+        code.push_str("    },\n");
+    }
+
+    code.push_str("];\n");
+    let dest = Path::new(out_dir).join("effort_generated.rs");
+    fs::write(&dest, code).expect("write generated effort file");
+}
+
+fn read_tool_categories() -> ToolCategoryFile {
+    let raw = fs::read_to_string("data/tool_categories.toml").expect("read tool_categories.toml");
+    toml::from_str(&raw).expect("parse tool_categories.toml")
+}
+
+/// Apply the shared field rules from `rules.rs`, plus unique ids.
+fn validate_ecosystems(ecosystems: &[Ecosystem]) {
+    let categories = read_tool_categories();
+    let is_known_tool = |tool: &str| {
+        categories
+            .category
+            .iter()
+            .any(|c| c.tools.iter().any(|t| t == tool))
+    };
+
+    for (i, eco) in ecosystems.iter().enumerate() {
+        assert!(
+            !ecosystems[..i].iter().any(|e| e.id == eco.id),
+            "ecosystems.toml: duplicate id {:?}",
+            eco.id
+        );
+
+        let mut deploy_modes: Vec<(&str, &[String])> = eco
+            .deploy_modes
+            .iter()
+            .flatten()
+            .map(|(mode, dm)| (mode.as_str(), dm.languages.as_slice()))
+            .collect();
+        deploy_modes.sort();
+        let fields = EcosystemFields {
+            id: &eco.id,
+            name: &eco.name,
+            short: &eco.short,
+            languages: &eco.languages,
+            vm: &eco.vm,
+            transaction_model: &eco.transaction_model,
+            evm_compatibility: &eco.evm_compatibility,
+            deployment_options: &eco.deployment_options,
+            chain_layer: &eco.chain_layer,
+            consensus: &eco.consensus,
+            ratings: [
+                ("l2_maturity", eco.l2_maturity),
+                ("tooling_maturity", eco.tooling_maturity),
+                ("doc_quality", eco.doc_quality),
+                ("ecosystem_funding", eco.ecosystem_funding),
+            ],
+            tooling: &eco.tooling,
+            deploy_modes,
+            provenance: eco
+                .provenance
+                .iter()
+                .map(|(field, p)| (field.as_str(), p.source.as_str(), p.last_verified.as_str()))
+                .collect(),
+        };
+        let errors = field_errors(&fields, is_known_tool);
+        assert!(
+            errors.is_empty(),
+            "ecosystems.toml: {}: {}",
+            eco.id,
+            errors.join("; ")
+        );
+    }
+}
+
+fn generate_tool_categories(out_dir: &str) {
+    let file = read_tool_categories();
+
+    let mut code = String::from("pub static TOOL_CATEGORIES: &[ToolCategory] = &[\n");
+    for cat in &file.category {
+        code.push_str(&format!(
+            "    ToolCategory {{ id: {}, name: {}, tools: {} }},\n",
+            quote(&cat.id),
+            quote(&cat.name),
+            str_slice(&cat.tools)
+        ));
+    }
+    code.push_str("];\n");
+
+    let dest = Path::new(out_dir).join("tool_categories_generated.rs");
+    fs::write(&dest, code).expect("write generated tool categories file");
+}
+
+/// FNV-1a over the bundled data files, used as the dataset version stamped
+/// into exports, and the changelog from `changelog.toml`.
+fn generate_dataset_version(out_dir: &str) {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for path in DATA_FILES {
+        for byte in fs::read(path).expect("read data file") {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    let mut code = format!("pub const DATASET_VERSION: &str = \"{:016x}\";\n", hash);
+
+    let raw = fs::read_to_string("data/changelog.toml").expect("read changelog.toml");
+    let file: ChangelogFile = toml::from_str(&raw).expect("parse changelog.toml");
+    assert!(!file.release.is_empty(), "changelog.toml: no releases");
+
+    code.push_str("pub static CHANGELOG: &[DatasetRelease] = &[\n");
+    for (i, release) in file.release.iter().enumerate() {
+        assert!(
+            !file.release[..i]
+                .iter()
+                .any(|r| r.version == release.version),
+            "changelog.toml: duplicate version {:?}",
+            release.version
+        );
+        assert!(
+            is_iso_date(&release.date),
+            "changelog.toml: {}: date {:?} is not YYYY-MM-DD",
+            release.version,
+            release.date
+        );
+        assert!(
+            !release.changes.is_empty(),
+            "changelog.toml: {}: no changes listed",
+            release.version
+        );
+        code.push_str(&format!(
+            "    DatasetRelease {{ version: {}, date: {}, changes: {} }},\n",
+            quote(&release.version),
+            quote(&release.date),
+            str_slice(&release.changes)
+        ));
+    }
+    code.push_str("];\n");
+
+    let dest = Path::new(out_dir).join("dataset_generated.rs");
+    fs::write(&dest, code).expect("write generated dataset file");
+}
+
+fn generate_overrides(ecosystems: &[Ecosystem], out_dir: &str) {
+    let raw = fs::read_to_string("data/pair_overrides.toml").expect("read pair_overrides.toml");
+    let file: PairOverrideFile = toml::from_str(&raw).expect("parse pair_overrides.toml");
+
+    let has_mode = |id: &str, mode: &Option<String>| -> bool {
+        let Some(eco) = ecosystems.iter().find(|e| e.id == id) else {
+            panic!("pair_overrides.toml: unknown ecosystem id {:?}", id);
+        };
+        match mode {
+            None => true,
+            Some(m) => {
+                eco.deployment_options.contains(m)
+                    || eco.deploy_modes.as_ref().is_some_and(|d| d.contains_key(m))
+            }
+        }
+    };
+
+    let mut code = String::from("pub static PAIR_OVERRIDES: &[PairOverride] = &[\n");
+
+    for ov in &file.overrides {
+        assert!(
+            has_mode(&ov.source, &ov.src_mode),
+            "pair_overrides.toml: {} has no deploy mode {:?}",
+            ov.source,
+            ov.src_mode
+        );
+        assert!(
+            has_mode(&ov.dest, &ov.dst_mode),
+            "pair_overrides.toml: {} has no deploy mode {:?}",
+            ov.dest,
+            ov.dst_mode
+        );
+
+        let mut keys: Vec<&String> = ov.adjust.keys().collect();
+        keys.sort();
+        let adjustments: Vec<String> = keys
+            .into_iter()
+            .map(|k| {
+                let variant = dimension_variant(k).unwrap_or_else(|| {
+                    panic!("pair_overrides.toml: unknown dimension {:?}", k)
+                });
+                format!("(Dimension::{}, {:?})", variant, ov.adjust[k])
+            })
+            .collect();
+
+        code.push_str("    PairOverride {\n");
+        code.push_str(&format!("        source: {},\n", quote(&ov.source)));
+        code.push_str(&format!("        dest: {},\n", quote(&ov.dest)));
+        code.push_str(&format!("        src_mode: {},\n", opt_str(&ov.src_mode)));
+        code.push_str(&format!("        dst_mode: {},\n", opt_str(&ov.dst_mode)));
+        code.push_str(&format!("        adjustments: &[{}],\n", adjustments.join(", ")));
+        code.push_str(&format!("        positives: {},\n", str_slice(&ov.positives)));
+        code.push_str(&format!("        challenges: {},\n", str_slice(&ov.challenges)));
+        code.push_str(&format!(
+            "        justification: {},\n",
+            quote(&ov.justification)
+        ));
+        code.push_str(&format!("        source_url: {},\n", quote(&ov.source_url)));
+        code.push_str("    },\n");
+    }
+
+    code.push_str("];\n");
+    let dest = Path::new(out_dir).join("pair_overrides_generated.rs");
+    fs::write(&dest, code).expect("write generated overrides file");
+}
+
+const DATA_FILES: [&str; 5] = [
+    "data/ecosystems.toml",
+    "data/pair_overrides.toml",
+    "data/mitigations.toml",
+    "data/effort.toml",
+    "data/tool_categories.toml",
+];
+
+fn main() {
+    for path in DATA_FILES {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+    println!("cargo:rerun-if-changed=data/changelog.toml");
+    println!("cargo:rerun-if-changed=src/data/rules.rs");
+
+    let raw = fs::read_to_string("data/ecosystems.toml").expect("read ecosystems.toml");
+    let file: EcosystemFile = toml::from_str(&raw).expect("parse ecosystems.toml");
+
+    validate_ecosystems(&file.ecosystem);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&out_dir).join("ecosystems_generated.rs");
+
+    let mut code = String::from("pub fn load_ecosystems() -> Vec<Ecosystem> {\n    vec![\n");
+
+    for eco in &file.ecosystem {
+        code.push_str("        Ecosystem {\n");
+        code.push_str(&format!("            id: {}.into(),\n", quote(&eco.id)));
+        code.push_str(&format!("            name: {}.into(),\n", quote(&eco.name)));
+        code.push_str(&format!("            short: {}.into(),\n", quote(&eco.short)));
+        code.push_str(&format!("            languages: {},\n", str_vec(&eco.languages)));
+        code.push_str(&format!("            vm: {}.into(),\n", quote(&eco.vm)));
+        code.push_str(&format!(
+            "            transaction_model: {}.into(),\n",
+            quote(&eco.transaction_model)
+        ));
+        code.push_str(&format!(
+            "            evm_compatibility: {}.into(),\n",
+            quote(&eco.evm_compatibility)
+        ));
+        code.push_str(&format!(
+            "            deployment_options: {},\n",
+            str_vec(&eco.deployment_options)
+        ));
+        code.push_str(&format!(
+            "            chain_layer: {}.into(),\n",
+            quote(&eco.chain_layer)
+        ));
+        code.push_str(&format!("            l2_maturity: {},\n", eco.l2_maturity));
+        code.push_str(&format!(
+            "            consensus: {}.into(),\n",
+            quote(&eco.consensus)
+        ));
+        code.push_str(&format!(
+            "            tooling_maturity: {},\n",
+            eco.tooling_maturity
+        ));
+        code.push_str(&format!("            tooling: {},\n", str_vec(&eco.tooling)));
+        code.push_str(&format!("            doc_quality: {},\n", eco.doc_quality));
+        code.push_str(&format!(
+            "            ecosystem_funding: {},\n",
+            eco.ecosystem_funding
+        ));
+        code.push_str(&format!(
+            "            group: {}.into(),\n",
+            quote(&eco.group)
+        ));
+        match eco.position {
+            None => code.push_str("            position: None,\n"),
+            Some([x, y]) => code.push_str(&format!(
+                "            position: Some([{:.1}, {:.1}]),\n",
+                x, y
+            )),
+        }
+
+        match &eco.deploy_modes {
+            None => code.push_str("            deploy_modes: None,\n"),
+            Some(modes) => {
+                code.push_str("            deploy_modes: Some(HashMap::from([\n");
+                for (key, dm) in modes {
+                    code.push_str(&format!(
+                        "                ({}.into(), DeployMode {{ languages: {} }}),\n",
+                        quote(key),
+                        str_vec(&dm.languages)
+                    ));
+                }
+                code.push_str("            ])),\n");
+            }
+        }
+
+        if eco.provenance.is_empty() {
+            code.push_str("            provenance: BTreeMap::new(),\n");
+        } else {
+            code.push_str("            provenance: BTreeMap::from([\n");
+            for (field, p) in &eco.provenance {
+                code.push_str(&format!(
+                    "                ({}.into(), Provenance {{ source: {}.into(), last_verified: {}.into() }}),\n",
+                    quote(field),
+                    quote(&p.source),
+                    quote(&p.last_verified)
+                ));
+            }
+            code.push_str("            ]),\n");
+        }
+
+        code.push_str("        },\n");
+    }
+
+    code.push_str("    ]\n}\n");
+    fs::write(&dest, code).expect("write generated file");
+
+    generate_overrides(&file.ecosystem, &out_dir);
+    generate_mitigations(&file.ecosystem, &out_dir);
+    generate_effort(&out_dir);
+    generate_tool_categories(&out_dir);
+    generate_dataset_version(&out_dir);
+}
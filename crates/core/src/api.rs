@@ -0,0 +1,216 @@
+//! JSON API responses shared by the static API written at build time, the
+//! local HTTP server and the CLI.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::data::dataset::DATASET_VERSION;
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::{compute_migration_weighted, MigrationReport, Weights};
+
+#[derive(Serialize)]
+pub struct EcosystemList<'a> {
+    pub dataset_version: &'static str,
+    pub ecosystems: &'a [Ecosystem],
+}
+
+#[derive(Serialize)]
+pub struct EcosystemResponse<'a> {
+    pub dataset_version: &'static str,
+    pub ecosystem: &'a Ecosystem,
+    /// Deploy modes that have their own compare variants.
+    pub modes: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CompareResponse<'a> {
+    pub dataset_version: &'static str,
+    pub source: &'a str,
+    pub dest: &'a str,
+    pub src_mode: Option<&'a str>,
+    pub dst_mode: Option<&'a str>,
+    pub weights: Weights,
+    pub report: MigrationReport,
+}
+
+impl<'a> CompareResponse<'a> {
+    pub fn new(
+        src: &'a Ecosystem,
+        dst: &'a Ecosystem,
+        src_mode: Option<&'a str>,
+        dst_mode: Option<&'a str>,
+    ) -> Self {
+        Self::weighted(src, dst, src_mode, dst_mode, Weights::default())
+    }
+
+    pub fn weighted(
+        src: &'a Ecosystem,
+        dst: &'a Ecosystem,
+        src_mode: Option<&'a str>,
+        dst_mode: Option<&'a str>,
+        weights: Weights,
+    ) -> Self {
+        CompareResponse {
+            dataset_version: DATASET_VERSION,
+            source: &src.id,
+            dest: &dst.id,
+            src_mode,
+            dst_mode,
+            weights,
+            report: compute_migration_weighted(src, dst, src_mode, dst_mode, &weights),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RankEntry<'a> {
+    pub dest: &'a str,
+    pub name: &'a str,
+    pub overall: f64,
+    pub difficulty_label: String,
+}
+
+/// Every other ecosystem ranked by migration difficulty from one source,
+/// easiest first.
+#[derive(Serialize)]
+pub struct RankResponse<'a> {
+    pub dataset_version: &'static str,
+    pub source: &'a str,
+    pub src_mode: Option<&'a str>,
+    pub weights: Weights,
+    pub ranking: Vec<RankEntry<'a>>,
+}
+
+impl<'a> RankResponse<'a> {
+    pub fn new(
+        src: &'a Ecosystem,
+        src_mode: Option<&'a str>,
+        ecosystems: &'a [Ecosystem],
+        weights: Weights,
+    ) -> Self {
+        let mut ranking: Vec<RankEntry> = ecosystems
+            .iter()
+            .filter(|d| d.id != src.id)
+            .map(|dst| {
+                let report = compute_migration_weighted(src, dst, src_mode, None, &weights);
+                RankEntry {
+                    dest: &dst.id,
+                    name: &dst.name,
+                    overall: report.overall,
+                    difficulty_label: report.difficulty_label,
+                }
+            })
+            .collect();
+        ranking.sort_by(|a, b| a.overall.total_cmp(&b.overall));
+
+        RankResponse {
+            dataset_version: DATASET_VERSION,
+            source: &src.id,
+            src_mode,
+            weights,
+            ranking,
+        }
+    }
+}
+
+/// Validate a requested deploy mode against the ecosystem's options.
+pub fn deploy_mode(eco: &Ecosystem, mode: Option<&str>) -> Result<Option<String>, String> {
+    let options = eco.mode_options();
+    match mode {
+        None => Ok(None),
+        Some(m) if options.iter().any(|o| o == m) => Ok(Some(m.to_string())),
+        Some(m) => Err(format!(
+            "{} has no deploy mode {:?} (options: {})",
+            eco.id,
+            m,
+            options.join(", ")
+        )),
+    }
+}
+
+/// Deploy modes the migration panel offers a toggle for.
+pub fn selectable_modes(eco: &Ecosystem) -> Vec<String> {
+    let options = eco.mode_options();
+    if eco.deploy_modes.is_some() && options.len() > 1 {
+        options
+    } else {
+        Vec::new()
+    }
+}
+
+/// `None` plus every selectable mode, for enumerating compare variants.
+pub fn mode_variants(eco: &Ecosystem) -> Vec<Option<String>> {
+    std::iter::once(None)
+        .chain(selectable_modes(eco).into_iter().map(Some))
+        .collect()
+}
+
+/// Path segment for an ecosystem, with `.mode` appended for mode variants.
+pub fn path_key(eco: &Ecosystem, mode: Option<&str>) -> String {
+    match mode {
+        Some(m) => format!("{}.{}", eco.id, m),
+        None => eco.id.clone(),
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Write the read-only static API under `dir`:
+///
+/// - `ecosystems.json`
+/// - `ecosystems/<id>.json`
+/// - `compare/<src>/<dst>.json`, plus `compare/<src>.<mode>/<dst>.<mode>.json`
+///   for every deploy mode combination
+///
+/// Returns the number of files written.
+pub fn write_static_api(dir: &Path, ecosystems: &[Ecosystem]) -> io::Result<usize> {
+    let mut written = 0;
+
+    write_json(
+        &dir.join("ecosystems.json"),
+        &EcosystemList {
+            dataset_version: DATASET_VERSION,
+            ecosystems,
+        },
+    )?;
+    written += 1;
+
+    for eco in ecosystems {
+        write_json(
+            &dir.join("ecosystems").join(format!("{}.json", eco.id)),
+            &EcosystemResponse {
+                dataset_version: DATASET_VERSION,
+                ecosystem: eco,
+                modes: selectable_modes(eco),
+            },
+        )?;
+        written += 1;
+    }
+
+    for src in ecosystems {
+        for dst in ecosystems.iter().filter(|d| d.id != src.id) {
+            for src_mode in mode_variants(src) {
+                for dst_mode in mode_variants(dst) {
+                    let (sm, dm) = (src_mode.as_deref(), dst_mode.as_deref());
+                    let path = dir
+                        .join("compare")
+                        .join(path_key(src, sm))
+                        .join(format!("{}.json", path_key(dst, dm)));
+                    write_json(&path, &CompareResponse::new(src, dst, sm, dm))?;
+                    written += 1;
+                }
+            }
+        }
+    }
+
+    Ok(written)
+}
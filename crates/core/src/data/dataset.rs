@@ -0,0 +1,277 @@
+//! The dataset as a whole: its version and changelog, reading and writing it
+//! as TOML or JSON, and field-level overlays for trying corrections at
+//! runtime.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::ecosystem::Ecosystem;
+
+/// A `changelog.toml` entry.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct DatasetRelease {
+    pub version: &'static str,
+    pub date: &'static str,
+    pub changes: &'static [&'static str],
+}
+
+// Content hash of the bundled data files and the changelog, newest release
+// first, generated by build.rs.
+include!(concat!(env!("OUT_DIR"), "/dataset_generated.rs"));
+
+/// The latest changelog version.
+pub fn dataset_release() -> &'static str {
+    CHANGELOG[0].version
+}
+
+/// Releases newer than `version`, newest first; all of them if it isn't in
+/// the changelog.
+pub fn releases_since(version: &str) -> &'static [DatasetRelease] {
+    let end = CHANGELOG
+        .iter()
+        .position(|r| r.version == version)
+        .unwrap_or(CHANGELOG.len());
+    &CHANGELOG[..end]
+}
+
+/// Layout of `ecosystems.toml`.
+#[derive(Serialize, Deserialize)]
+struct DatasetFile {
+    ecosystem: Vec<Ecosystem>,
+}
+
+/// One field change, written `set <id>.<field> = <value>`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Patch {
+    pub id: String,
+    pub field: String,
+    pub value: Value,
+}
+
+impl Patch {
+    pub fn line(&self) -> String {
+        format!("set {}.{} = {}", self.id, self.field, self.value)
+    }
+}
+
+/// A one-line TOML error: the message, or where parsing stopped.
+fn toml_error(e: &toml::de::Error) -> String {
+    match (e.message().trim(), e.span()) {
+        ("", Some(span)) => format!("TOML: syntax error at byte {}", span.start),
+        ("", None) => "TOML: syntax error".to_string(),
+        (msg, _) => format!("TOML: {}", msg),
+    }
+}
+
+/// Problems across a whole dataset, prefixed with the ecosystem id.
+pub fn validate_dataset(ecosystems: &[Ecosystem]) -> Vec<String> {
+    ecosystems
+        .iter()
+        .enumerate()
+        .flat_map(|(i, eco)| {
+            let others: Vec<Ecosystem> = ecosystems
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, e)| e.clone())
+                .collect();
+            eco.validate(&others)
+                .into_iter()
+                .map(move |e| format!("{}: {}", eco.id, e))
+        })
+        .collect()
+}
+
+/// Read a full dataset: `ecosystems.toml` layout, or JSON as either an array
+/// of ecosystems or `{"ecosystem": [...]}`. Unlike `parse_dataset`, the
+/// build's rules aren't checked, so older revisions still load.
+pub fn read_dataset(text: &str) -> Result<Vec<Ecosystem>, String> {
+    let ecosystems = if text.trim_start().starts_with(['[', '{']) {
+        match serde_json::from_str::<Vec<Ecosystem>>(text) {
+            Ok(ecos) => Ok(ecos),
+            Err(_) => serde_json::from_str::<DatasetFile>(text).map(|f| f.ecosystem),
+        }
+        .map_err(|e| e.to_string())
+    } else {
+        Err(String::new())
+    };
+    // JSON-looking text may still be TOML that starts with `[[ecosystem]]`.
+    let ecosystems = match ecosystems {
+        Ok(ecos) => ecos,
+        Err(json_err) => match toml::from_str::<DatasetFile>(text) {
+            Ok(file) => file.ecosystem,
+            Err(e) if json_err.is_empty() => return Err(toml_error(&e)),
+            Err(e) => {
+                return Err(format!(
+                    "not a JSON ({}) or TOML ({}) dataset",
+                    json_err,
+                    toml_error(&e)
+                ))
+            }
+        },
+    };
+
+    if ecosystems.is_empty() {
+        return Err("the dataset has no ecosystems".to_string());
+    }
+    Ok(ecosystems)
+}
+
+/// `read_dataset`, checked against the build's rules.
+pub fn parse_dataset(text: &str) -> Result<Vec<Ecosystem>, String> {
+    let ecosystems = read_dataset(text)?;
+    let errors = validate_dataset(&ecosystems);
+    if errors.is_empty() {
+        Ok(ecosystems)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// A value as written after `=`: a TOML literal, or a bare word taken as a
+/// string, so `set base.vm = EVM` works without quotes.
+fn parse_value(raw: &str) -> Value {
+    toml::from_str::<toml::Table>(&format!("v = {}", raw))
+        .ok()
+        .and_then(|mut t| t.remove("v"))
+        .and_then(|v| serde_json::to_value(v).ok())
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+fn parse_set_line(line: &str) -> Result<Patch, String> {
+    let rest = line
+        .strip_prefix("set ")
+        .ok_or_else(|| format!("expected `set <id>.<field> = <value>`, got {:?}", line))?;
+    let (target, value) = rest
+        .split_once('=')
+        .ok_or_else(|| format!("missing `=` in {:?}", line))?;
+    let (id, field) = target
+        .trim()
+        .split_once('.')
+        .ok_or_else(|| format!("expected <id>.<field> in {:?}", line))?;
+    Ok(Patch {
+        id: id.to_string(),
+        field: field.to_string(),
+        value: parse_value(value.trim()),
+    })
+}
+
+/// Read overlay patches: `set <id>.<field> = <value>` lines (blank lines and
+/// `#` comments allowed), or a JSON/TOML table of fields per ecosystem id,
+/// e.g. `[base]` / `ecosystem_funding = 4`.
+pub fn parse_overlay(text: &str) -> Result<Vec<Patch>, String> {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect();
+    if lines.is_empty() {
+        return Err("the overlay is empty".to_string());
+    }
+    if lines[0].starts_with("set ") {
+        return lines.into_iter().map(parse_set_line).collect();
+    }
+
+    let table: serde_json::Map<String, Value> = match serde_json::from_str(text) {
+        Ok(map) => map,
+        Err(_) => {
+            let table: toml::Table = toml::from_str(text).map_err(|e| toml_error(&e))?;
+            match serde_json::to_value(table) {
+                Ok(Value::Object(map)) => map,
+                _ => return Err("the overlay must be a table".to_string()),
+            }
+        }
+    };
+
+    let mut patches = Vec::new();
+    for (id, fields) in table {
+        let Value::Object(fields) = fields else {
+            return Err(format!("{}: expected a table of fields", id));
+        };
+        for (field, value) in fields {
+            patches.push(Patch {
+                id: id.clone(),
+                field,
+                value,
+            });
+        }
+    }
+    Ok(patches)
+}
+
+/// Apply patches in order. Fails on unknown ids or fields, values of the
+/// wrong type, or a result that breaks the build's rules.
+pub fn apply_overlay(ecosystems: &mut [Ecosystem], patches: &[Patch]) -> Result<(), String> {
+    for patch in patches {
+        if patch.field == "id" {
+            return Err(format!("{}: ids can't be patched", patch.line()));
+        }
+        let eco = ecosystems
+            .iter_mut()
+            .find(|e| e.id == patch.id)
+            .ok_or_else(|| format!("{}: no ecosystem {:?}", patch.line(), patch.id))?;
+
+        let mut value = serde_json::to_value(&*eco).map_err(|e| e.to_string())?;
+        let Some(slot) = value.get_mut(&patch.field) else {
+            return Err(format!("{}: no field {:?}", patch.line(), patch.field));
+        };
+        *slot = patch.value.clone();
+        *eco = serde_json::from_value(value).map_err(|e| format!("{}: {}", patch.line(), e))?;
+    }
+
+    let errors = validate_dataset(ecosystems);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// The field changes that turn `original` into `edited`, by field name.
+pub fn ecosystem_patches(original: &Ecosystem, edited: &Ecosystem) -> Vec<Patch> {
+    let (Ok(Value::Object(before)), Ok(Value::Object(after))) =
+        (serde_json::to_value(original), serde_json::to_value(edited))
+    else {
+        return Vec::new();
+    };
+    after
+        .into_iter()
+        .filter(|(field, value)| before.get(field) != Some(value))
+        .map(|(field, value)| Patch {
+            id: original.id.clone(),
+            field,
+            value,
+        })
+        .collect()
+}
+
+/// Patches as a TOML overlay, one table per ecosystem, which
+/// `parse_overlay` reads back. Unset optional fields can't be written and
+/// are left out.
+pub fn overlay_toml(patches: &[Patch]) -> String {
+    let mut root = toml::Table::new();
+    for patch in patches {
+        let Ok(value) = toml::Value::try_from(&patch.value) else {
+            continue;
+        };
+        if let toml::Value::Table(fields) = root
+            .entry(patch.id.clone())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        {
+            fields.insert(patch.field.clone(), value);
+        }
+    }
+    toml::to_string(&root).unwrap_or_default()
+}
+
+/// The dataset in `ecosystems.toml` layout.
+pub fn dataset_toml(ecosystems: &[Ecosystem]) -> String {
+    let file = DatasetFile {
+        ecosystem: ecosystems.to_vec(),
+    };
+    toml::to_string(&file).expect("serialize dataset")
+}
+
+pub fn dataset_json(ecosystems: &[Ecosystem]) -> String {
+    serde_json::to_string_pretty(ecosystems).expect("serialize dataset")
+}
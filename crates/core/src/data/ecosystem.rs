@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::rules::{field_errors, EcosystemFields};
+use crate::data::tools::tool_category;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeployMode {
+    pub languages: Vec<String>,
+}
+
+/// Where an attribute's value comes from and when it was last checked.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub source: String,
+    /// `YYYY-MM-DD`.
+    pub last_verified: String,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ecosystem {
+    pub id: String,
+    pub name: String,
+    pub short: String,
+    pub languages: Vec<String>,
+    pub vm: String,
+    pub transaction_model: String,
+    pub evm_compatibility: String,
+    pub deployment_options: Vec<String>,
+    pub chain_layer: String,
+    pub l2_maturity: u8,
+    pub consensus: String,
+    pub tooling_maturity: u8,
+    pub tooling: Vec<String>,
+    pub doc_quality: u8,
+    pub ecosystem_funding: u8,
+    /// Grid group; cards of a group are laid out together.
+    #[serde(default)]
+    pub group: String,
+    /// Pinned grid position, overriding the computed layout.
+    #[serde(default)]
+    pub position: Option<[f64; 2]>,
+    #[serde(default)]
+    pub deploy_modes: Option<HashMap<String, DeployMode>>,
+    /// Provenance per attribute, keyed by field name or `default`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub provenance: BTreeMap<String, Provenance>,
+}
+
+impl Ecosystem {
+    /// Every deploy mode a comparison can name: the deployment options, then
+    /// modes only `deploy_modes` lists, such as an EVM layer, by name.
+    pub fn mode_options(&self) -> Vec<String> {
+        let mut extra: Vec<&String> = self
+            .deploy_modes
+            .iter()
+            .flat_map(|modes| modes.keys())
+            .filter(|mode| !self.deployment_options.contains(mode))
+            .collect();
+        extra.sort();
+        self.deployment_options
+            .iter()
+            .chain(extra)
+            .cloned()
+            .collect()
+    }
+
+    /// The provenance of an attribute: its own entry, or the default.
+    pub fn provenance(&self, field: &str) -> Option<&Provenance> {
+        self.provenance
+            .get(field)
+            .or_else(|| self.provenance.get("default"))
+    }
+
+    /// Check an ecosystem edited at runtime against the rules the build
+    /// applies to the bundled data. `others` is the rest of the dataset,
+    /// whose ids it must not reuse. Tools missing from `tool_categories.toml`
+    /// are allowed here; see [`Ecosystem::unknown_tools`].
+    pub fn validate(&self, others: &[Ecosystem]) -> Vec<String> {
+        let mut deploy_modes: Vec<(&str, &[String])> = self
+            .deploy_modes
+            .iter()
+            .flatten()
+            .map(|(mode, dm)| (mode.as_str(), dm.languages.as_slice()))
+            .collect();
+        deploy_modes.sort();
+        let fields = EcosystemFields {
+            id: &self.id,
+            name: &self.name,
+            short: &self.short,
+            languages: &self.languages,
+            vm: &self.vm,
+            transaction_model: &self.transaction_model,
+            evm_compatibility: &self.evm_compatibility,
+            deployment_options: &self.deployment_options,
+            chain_layer: &self.chain_layer,
+            consensus: &self.consensus,
+            ratings: [
+                ("l2_maturity", self.l2_maturity),
+                ("tooling_maturity", self.tooling_maturity),
+                ("doc_quality", self.doc_quality),
+                ("ecosystem_funding", self.ecosystem_funding),
+            ],
+            tooling: &self.tooling,
+            deploy_modes,
+            provenance: self
+                .provenance
+                .iter()
+                .map(|(field, p)| (field.as_str(), p.source.as_str(), p.last_verified.as_str()))
+                .collect(),
+        };
+
+        let mut errors = field_errors(&fields, |_| true);
+        if others.iter().any(|e| e.id == self.id) {
+            errors.insert(0, format!("id {:?} is already taken", self.id));
+        }
+        errors
+    }
+
+    /// Tools not in `tool_categories.toml`. The build rejects these for the
+    /// bundled data; custom and uploaded ecosystems only get a warning, as
+    /// the tools just go uncategorized in migration plans.
+    pub fn unknown_tools(&self) -> Vec<&str> {
+        self.tooling
+            .iter()
+            .map(String::as_str)
+            .filter(|tool| tool_category(tool).is_none())
+            .collect()
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/ecosystems_generated.rs"));
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+use crate::data::scoring::{Dimension, MigrationReport};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Learning,
+    Rewrite,
+    Tooling,
+    Audit,
+    Deployment,
+}
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Learning => "Learning",
+            Phase::Rewrite => "Rewrite",
+            Phase::Tooling => "Tooling / CI",
+            Phase::Audit => "Audit",
+            Phase::Deployment => "Deployment / Infra",
+        }
+    }
+}
+
+/// Per-phase coefficients, loaded from `data/effort.toml` at compile time.
+pub struct PhaseCoefficients {
+    pub phase: Phase,
+    pub base: f64,
+    pub per_dev: f64,
+    pub per_kloc: f64,
+    pub per_contract: f64,
+    pub floor: f64,
+    pub coverage_penalty: f64,
+    pub dimensions: &'static [(Dimension, f64)],
+}
+
+pub struct RangeCoefficients {
+    pub low: f64,
+    pub high: f64,
+    pub docs_spread: f64,
+}
+
+include!(concat!(env!("OUT_DIR"), "/effort_generated.rs"));
+
+/// The project being migrated.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct ProjectProfile {
+    pub contracts: u32,
+    pub loc: u32,
+    pub team_size: u32,
+    /// Test coverage as a fraction, 0.0–1.0.
+    pub test_coverage: f64,
+}
+
+impl Default for ProjectProfile {
+    fn default() -> Self {
+        ProjectProfile {
+            contracts: 10,
+            loc: 3000,
+            team_size: 3,
+            test_coverage: 0.7,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct PhaseEstimate {
+    pub phase: Phase,
+    pub low: f64,
+    pub high: f64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct EffortEstimate {
+    pub phases: Vec<PhaseEstimate>,
+    /// Total person-weeks, low end.
+    pub low: f64,
+    /// Total person-weeks, high end.
+    pub high: f64,
+}
+
+impl EffortEstimate {
+    /// Calendar weeks for the whole team, assuming work parallelizes evenly.
+    pub fn calendar_weeks(&self, team_size: u32) -> (f64, f64) {
+        let team = team_size.max(1) as f64;
+        (self.low / team, self.high / team)
+    }
+}
+
+/// Weighted mean of the report's dimension scores.
+fn phase_difficulty(report: &MigrationReport, dimensions: &[(Dimension, f64)]) -> f64 {
+    let total_weight: f64 = dimensions.iter().map(|(_, w)| w).sum();
+    if total_weight == 0.0 {
+        return 0.0;
+    }
+    let weighted: f64 = dimensions
+        .iter()
+        .map(|&(dim, w)| report.score(dim) * w)
+        .sum();
+    weighted / total_weight
+}
+
+pub fn estimate_effort(report: &MigrationReport, project: &ProjectProfile) -> EffortEstimate {
+    let kloc = project.loc as f64 / 1000.0;
+    let contracts = project.contracts as f64;
+    let team = project.team_size as f64;
+    let coverage_gap = 1.0 - project.test_coverage.clamp(0.0, 1.0);
+
+    let range = &EFFORT_RANGE;
+    let high_factor = range.high + range.docs_spread * report.score(Dimension::Docs);
+
+    let phases: Vec<PhaseEstimate> = PHASE_COEFFICIENTS
+        .iter()
+        .map(|c| {
+            let size = c.base + c.per_dev * team + c.per_kloc * kloc + c.per_contract * contracts;
+            let difficulty = phase_difficulty(report, c.dimensions);
+            let point = size
+                * (c.floor + (1.0 - c.floor) * difficulty)
+                * (1.0 + c.coverage_penalty * coverage_gap);
+            PhaseEstimate {
+                phase: c.phase,
+                low: point * range.low,
+                high: point * high_factor,
+            }
+        })
+        .collect();
+
+    EffortEstimate {
+        low: phases.iter().map(|p| p.low).sum(),
+        high: phases.iter().map(|p| p.high).sum(),
+        phases,
+    }
+}
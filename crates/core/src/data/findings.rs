@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+use crate::data::mitigations::Mitigation;
+use crate::data::scoring::Dimension;
+
+/// What a finding is about. Text is rendered by the presentation layer
+/// (see `crate::text`), so the kind carries no wording of its own.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    // Positives
+    SameLanguage,
+    RelatedLanguageFamily,
+    SameVm,
+    BothEvmNative,
+    SameTxModel,
+    SameDeployModel,
+    ExcellentTooling,
+    StrongDocs,
+    WellFunded,
+    // Challenges
+    LanguageChange,
+    RelatedLanguageChange,
+    VmChange,
+    TxModelChange,
+    LeavingEvm,
+    EnteringEvm,
+    DeployModelChange,
+    ImmatureTooling,
+    LimitedDocs,
+    L2Gap,
+    LimitedFunding,
+    /// Free-form text from a curated pair override.
+    Curated,
+}
+
+/// How much a finding matters. Positives are always `Info`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Minor,
+    Major,
+    Critical,
+}
+
+/// Values a finding refers to. Which fields are set depends on the kind.
+#[derive(Clone, PartialEq, Default, Debug, Serialize)]
+pub struct FindingParams {
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    /// A 1–5 destination rating (tooling, docs, funding).
+    pub rating: Option<u8>,
+    /// Curated text, only set for `FindingKind::Curated`.
+    pub note: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub severity: Severity,
+    pub dimension: Option<Dimension>,
+    pub params: FindingParams,
+    /// Suggested next steps; only challenges carry them.
+    pub mitigations: Vec<Mitigation>,
+}
+
+impl Finding {
+    pub fn new(kind: FindingKind, severity: Severity, dimension: Option<Dimension>) -> Self {
+        Finding {
+            kind,
+            severity,
+            dimension,
+            params: FindingParams::default(),
+            mitigations: Vec::new(),
+        }
+    }
+
+    pub fn between<S: AsRef<str>>(mut self, from: &[S], to: &[S]) -> Self {
+        self.params.from = from.iter().map(|s| s.as_ref().to_string()).collect();
+        self.params.to = to.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    pub fn rating(mut self, rating: u8) -> Self {
+        self.params.rating = Some(rating);
+        self
+    }
+
+    pub fn note(mut self, note: &str) -> Self {
+        self.params.note = Some(note.to_string());
+        self
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Serialize;
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::findings::FindingKind;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MitigationCategory {
+    Compatibility,
+    Transpiler,
+    Framework,
+    Learning,
+}
+
+/// A concrete next step for a challenge, loaded from `data/mitigations.toml`.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct Mitigation {
+    pub category: MitigationCategory,
+    pub text: &'static str,
+    pub url: Option<&'static str>,
+}
+
+pub struct MitigationEntry {
+    pub kind: FindingKind,
+    pub dest: Option<&'static str>,
+    /// Source `evm_compatibility` values it applies to; empty for any.
+    pub src_evm: &'static [&'static str],
+    /// Deploy mode of the destination in which it doesn't apply.
+    pub unless_dst_mode: Option<&'static str>,
+    pub mitigation: Mitigation,
+}
+
+include!(concat!(env!("OUT_DIR"), "/mitigations_generated.rs"));
+
+impl MitigationEntry {
+    fn applies(&self, kind: FindingKind, src: &Ecosystem, dst_mode: Option<&str>) -> bool {
+        self.kind == kind
+            && (self.src_evm.is_empty() || self.src_evm.contains(&src.evm_compatibility.as_str()))
+            && (self.unless_dst_mode.is_none() || self.unless_dst_mode != dst_mode)
+    }
+}
+
+/// Mitigations for a challenge kind on a route, destination-specific entries
+/// first.
+pub fn mitigations_for(
+    kind: FindingKind,
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    dst_mode: Option<&str>,
+) -> Vec<Mitigation> {
+    let applies = |m: &&MitigationEntry| m.applies(kind, src, dst_mode);
+    let specific = MITIGATIONS
+        .iter()
+        .filter(|m| m.dest == Some(dst.id.as_str()))
+        .filter(applies);
+    let generic = MITIGATIONS
+        .iter()
+        .filter(|m| m.dest.is_none())
+        .filter(applies);
+    specific.chain(generic).map(|m| m.mitigation).collect()
+}
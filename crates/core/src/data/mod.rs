@@ -0,0 +1,11 @@
+pub mod dataset;
+pub mod ecosystem;
+pub mod effort;
+pub mod findings;
+pub mod mitigations;
+pub mod overrides;
+pub mod plan;
+pub mod provenance;
+pub mod rules;
+pub mod scoring;
+pub mod tools;
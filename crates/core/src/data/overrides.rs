@@ -0,0 +1,46 @@
+use serde::Serialize;
+
+use crate::data::scoring::Dimension;
+
+/// A curated adjustment for one source → dest pair, loaded from
+/// `data/pair_overrides.toml` at compile time.
+#[derive(Clone, PartialEq, Serialize)]
+pub struct PairOverride {
+    pub source: &'static str,
+    pub dest: &'static str,
+    pub src_mode: Option<&'static str>,
+    pub dst_mode: Option<&'static str>,
+    pub adjustments: &'static [(Dimension, f64)],
+    pub positives: &'static [&'static str],
+    pub challenges: &'static [&'static str],
+    pub justification: &'static str,
+    pub source_url: &'static str,
+}
+
+impl PairOverride {
+    /// Delta to apply to a dimension, or 0.0 if this override leaves it alone.
+    pub fn adjustment(&self, dim: Dimension) -> f64 {
+        self.adjustments
+            .iter()
+            .filter(|(d, _)| *d == dim)
+            .map(|(_, delta)| delta)
+            .sum()
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/pair_overrides_generated.rs"));
+
+/// Find the override for a pair. A mode-less override matches any mode.
+pub fn find_override(
+    source_id: &str,
+    dest_id: &str,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+) -> Option<&'static PairOverride> {
+    PAIR_OVERRIDES.iter().find(|ov| {
+        ov.source == source_id
+            && ov.dest == dest_id
+            && ov.src_mode.is_none_or(|m| src_mode == Some(m))
+            && ov.dst_mode.is_none_or(|m| dst_mode == Some(m))
+    })
+}
@@ -0,0 +1,208 @@
+use serde::Serialize;
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::{effective_languages, Dimension, MigrationReport};
+use crate::data::tools::{tool_category, TOOL_CATEGORIES};
+
+/// Plan sections, in the order steps appear.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanSection {
+    Skills,
+    Tooling,
+    Rewrite,
+    Deployment,
+    Audit,
+    Funding,
+}
+
+impl PlanSection {
+    pub const ALL: [PlanSection; 6] = [
+        PlanSection::Skills,
+        PlanSection::Tooling,
+        PlanSection::Rewrite,
+        PlanSection::Deployment,
+        PlanSection::Audit,
+        PlanSection::Funding,
+    ];
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepKind {
+    LearnLanguage,
+    LearnVm,
+    ReplaceTool,
+    AdoptTool,
+    RewriteForTxModel,
+    PortContracts,
+    RetestContracts,
+    ChooseDeployment,
+    DeployAs,
+    FullAudit,
+    DeltaAudit,
+    ApplyForGrants,
+    SeekFunding,
+}
+
+impl StepKind {
+    pub fn section(self) -> PlanSection {
+        match self {
+            StepKind::LearnLanguage | StepKind::LearnVm => PlanSection::Skills,
+            StepKind::ReplaceTool | StepKind::AdoptTool => PlanSection::Tooling,
+            StepKind::RewriteForTxModel | StepKind::PortContracts | StepKind::RetestContracts => {
+                PlanSection::Rewrite
+            }
+            StepKind::ChooseDeployment | StepKind::DeployAs => PlanSection::Deployment,
+            StepKind::FullAudit | StepKind::DeltaAudit => PlanSection::Audit,
+            StepKind::ApplyForGrants | StepKind::SeekFunding => PlanSection::Funding,
+        }
+    }
+}
+
+/// One checklist item. Wording is rendered by `crate::text`.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct PlanStep {
+    pub kind: StepKind,
+    /// What the step is about: a language, tool, transaction model, etc.
+    pub subject: String,
+    /// The destination-side counterpart, when there is one.
+    pub counterpart: Option<String>,
+    /// Tool category name for tooling steps.
+    pub category: Option<&'static str>,
+    /// Destination funding rating for funding steps.
+    pub rating: Option<u8>,
+}
+
+impl PlanStep {
+    fn new(kind: StepKind, subject: impl Into<String>) -> Self {
+        PlanStep {
+            kind,
+            subject: subject.into(),
+            counterpart: None,
+            category: None,
+            rating: None,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct MigrationPlan {
+    pub steps: Vec<PlanStep>,
+}
+
+fn tooling_steps(src: &Ecosystem, dst: &Ecosystem) -> Vec<PlanStep> {
+    let mut steps = Vec::new();
+
+    for cat in TOOL_CATEGORIES {
+        let in_cat = |tools: &[String]| -> Vec<String> {
+            tools
+                .iter()
+                .filter(|t| tool_category(t).is_some_and(|c| c.id == cat.id))
+                .cloned()
+                .collect()
+        };
+        let src_tools = in_cat(&src.tooling);
+        let dst_tools = in_cat(&dst.tooling);
+
+        // Pair unmatched source tools with unmatched destination tools in
+        // order, falling back to the first one once they run out.
+        let replacements: Vec<&String> = dst_tools
+            .iter()
+            .filter(|t| !src.tooling.contains(t))
+            .collect();
+        let unmatched = src_tools.iter().filter(|t| !dst.tooling.contains(t));
+
+        for (i, tool) in unmatched.enumerate() {
+            let mut step = PlanStep::new(StepKind::ReplaceTool, tool.clone());
+            step.counterpart = replacements
+                .get(i)
+                .or(replacements.first())
+                .map(|t| t.to_string());
+            step.category = Some(cat.name);
+            steps.push(step);
+        }
+
+        if src_tools.is_empty() {
+            if let Some(tool) = dst_tools.first() {
+                let mut step = PlanStep::new(StepKind::AdoptTool, tool.clone());
+                step.category = Some(cat.name);
+                steps.push(step);
+            }
+        }
+    }
+
+    steps
+}
+
+/// Build an ordered migration checklist from a report and its two ecosystems.
+pub fn generate_plan(
+    report: &MigrationReport,
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+) -> MigrationPlan {
+    let src_langs = effective_languages(src, src_mode);
+    let dst_langs = effective_languages(dst, dst_mode);
+    let mut steps = Vec::new();
+
+    // ── Skills ──
+    if !src_langs.iter().any(|l| dst_langs.contains(l)) {
+        for lang in dst_langs {
+            steps.push(PlanStep::new(StepKind::LearnLanguage, lang.clone()));
+        }
+    }
+    if report.score(Dimension::Vm) > 0.0 {
+        steps.push(PlanStep::new(StepKind::LearnVm, dst.vm.clone()));
+    }
+
+    // ── Tooling ──
+    steps.extend(tooling_steps(src, dst));
+
+    // ── Rewrite ──
+    if report.score(Dimension::TxModel) > 0.0 {
+        let mut step = PlanStep::new(StepKind::RewriteForTxModel, src.transaction_model.clone());
+        step.counterpart = Some(dst.transaction_model.clone());
+        steps.push(step);
+    }
+    if report.score(Dimension::Language) > 0.0 {
+        steps.push(PlanStep::new(StepKind::PortContracts, dst_langs.join(", ")));
+    } else {
+        steps.push(PlanStep::new(StepKind::RetestContracts, dst.name.clone()));
+    }
+
+    // ── Deployment ──
+    match dst_mode {
+        Some(mode) => steps.push(PlanStep::new(StepKind::DeployAs, mode)),
+        None if dst.deployment_options.len() > 1 => steps.push(PlanStep::new(
+            StepKind::ChooseDeployment,
+            dst.deployment_options.join(" / "),
+        )),
+        None => steps.push(PlanStep::new(
+            StepKind::DeployAs,
+            dst.deployment_options.join(" / "),
+        )),
+    }
+
+    // ── Audit ──
+    let rewritten = report.score(Dimension::Language) > 0.0 || report.score(Dimension::Vm) > 0.0;
+    let audit = if rewritten {
+        StepKind::FullAudit
+    } else {
+        StepKind::DeltaAudit
+    };
+    steps.push(PlanStep::new(audit, dst.name.clone()));
+
+    // ── Funding ──
+    let kind = if dst.ecosystem_funding >= 3 {
+        StepKind::ApplyForGrants
+    } else {
+        StepKind::SeekFunding
+    };
+    let mut step = PlanStep::new(kind, dst.name.clone());
+    step.rating = Some(dst.ecosystem_funding);
+    steps.push(step);
+
+    MigrationPlan { steps }
+}
@@ -0,0 +1,143 @@
+//! Which attributes feed each score, and how long ago they were verified.
+
+use serde::Serialize;
+
+use crate::data::ecosystem::{Ecosystem, Provenance};
+use crate::data::rules::{is_iso_date, PROVENANCE_FIELDS};
+use crate::data::scoring::Dimension;
+
+/// The attributes a dimension reads, as `(source fields, dest fields)`.
+pub fn dimension_fields(dim: Dimension) -> (&'static [&'static str], &'static [&'static str]) {
+    match dim {
+        Dimension::Language => (
+            &["languages", "deploy_modes"],
+            &["languages", "deploy_modes"],
+        ),
+        Dimension::Vm => (&["vm"], &["vm"]),
+        Dimension::TxModel => (&["transaction_model"], &["transaction_model"]),
+        Dimension::EvmCompat => (&["evm_compatibility"], &["evm_compatibility"]),
+        Dimension::Deploy => (&["deployment_options"], &["deployment_options"]),
+        Dimension::Tooling => (&[], &["tooling_maturity"]),
+        Dimension::Docs => (&[], &["doc_quality"]),
+        Dimension::L2 => (&["l2_maturity"], &["l2_maturity"]),
+        Dimension::Funding => (&[], &["ecosystem_funding"]),
+    }
+}
+
+/// Days since 1970-01-01 for a `YYYY-MM-DD` date.
+pub fn date_days(date: &str) -> Option<i64> {
+    if !is_iso_date(date) {
+        return None;
+    }
+    let y: i64 = date[0..4].parse().ok()?;
+    let m: i64 = date[5..7].parse().ok()?;
+    let d: i64 = date[8..10].parse().ok()?;
+    // Days from civil, proleptic Gregorian (Howard Hinnant's algorithm).
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe - 719468)
+}
+
+/// An attribute not verified within the allowed age.
+#[derive(Serialize)]
+pub struct StaleField {
+    pub id: String,
+    pub field: String,
+    /// `None` when the attribute has no provenance at all.
+    pub source: Option<String>,
+    pub last_verified: Option<String>,
+    pub age_days: Option<i64>,
+}
+
+impl StaleField {
+    pub fn describe(&self) -> String {
+        match (&self.last_verified, self.age_days, &self.source) {
+            (Some(date), Some(age), Some(source)) => format!(
+                "{}.{}: verified {} ({} days ago) against {}",
+                self.id, self.field, date, age, source
+            ),
+            _ => format!("{}.{}: no provenance", self.id, self.field),
+        }
+    }
+}
+
+/// The given attributes of an ecosystem last verified more than `max_age`
+/// days before `today` (days since 1970-01-01), or never.
+pub fn stale_fields(eco: &Ecosystem, fields: &[&str], today: i64, max_age: i64) -> Vec<StaleField> {
+    fields
+        .iter()
+        .filter_map(|&field| {
+            let provenance = eco.provenance(field);
+            let age = provenance
+                .and_then(|p| date_days(&p.last_verified))
+                .map(|d| today - d);
+            (age.is_none_or(|a| a > max_age)).then(|| StaleField {
+                id: eco.id.clone(),
+                field: field.to_string(),
+                source: provenance.map(|p| p.source.clone()),
+                last_verified: provenance.map(|p| p.last_verified.clone()),
+                age_days: age,
+            })
+        })
+        .collect()
+}
+
+/// Stale attributes across a dataset.
+pub fn stale_dataset(ecosystems: &[Ecosystem], today: i64, max_age: i64) -> Vec<StaleField> {
+    ecosystems
+        .iter()
+        .flat_map(|eco| stale_fields(eco, &PROVENANCE_FIELDS, today, max_age))
+        .collect()
+}
+
+/// Stale attributes behind each dimension of a migration, for dimensions
+/// that have any.
+pub fn stale_dimensions(
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    today: i64,
+    max_age: i64,
+) -> Vec<(Dimension, Vec<StaleField>)> {
+    Dimension::ALL
+        .iter()
+        .filter_map(|&dim| {
+            let (src_fields, dst_fields) = dimension_fields(dim);
+            let mut stale = stale_fields(src, src_fields, today, max_age);
+            stale.extend(stale_fields(dst, dst_fields, today, max_age));
+            (!stale.is_empty()).then_some((dim, stale))
+        })
+        .collect()
+}
+
+/// Hover text for the attributes behind a dimension, one line per
+/// ecosystem and source.
+pub fn provenance_text(src: &Ecosystem, dst: &Ecosystem, dim: Dimension) -> String {
+    let (src_fields, dst_fields) = dimension_fields(dim);
+    let mut lines = Vec::new();
+    for (eco, fields) in [(src, src_fields), (dst, dst_fields)] {
+        let mut groups: Vec<(Option<&Provenance>, Vec<&str>)> = Vec::new();
+        for &field in fields {
+            let p = eco.provenance(field);
+            match groups.iter_mut().find(|(q, _)| *q == p) {
+                Some((_, fields)) => fields.push(field),
+                None => groups.push((p, vec![field])),
+            }
+        }
+        for (p, fields) in groups {
+            lines.push(match p {
+                Some(p) => format!(
+                    "{} {}: {} (verified {})",
+                    eco.short,
+                    fields.join(", "),
+                    p.source,
+                    p.last_verified
+                ),
+                None => format!("{} {}: no source", eco.short, fields.join(", ")),
+            });
+        }
+    }
+    lines.join("\n")
+}
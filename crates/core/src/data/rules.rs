@@ -0,0 +1,150 @@
+// Field rules for ecosystems. build.rs `include!`s this file to check the
+// bundled data, and `Ecosystem::validate` applies it to ecosystems edited at
+// runtime, so it must not depend on the rest of the crate.
+
+/// Values the EVM compatibility score understands.
+pub const EVM_COMPATIBILITY: [&str; 3] = ["native", "supported", "none"];
+
+/// Attributes that carry provenance; `default` in the provenance table
+/// covers the ones without their own entry.
+pub const PROVENANCE_FIELDS: [&str; 13] = [
+    "languages",
+    "vm",
+    "transaction_model",
+    "evm_compatibility",
+    "deployment_options",
+    "deploy_modes",
+    "chain_layer",
+    "consensus",
+    "l2_maturity",
+    "tooling_maturity",
+    "tooling",
+    "doc_quality",
+    "ecosystem_funding",
+];
+
+/// A `YYYY-MM-DD` date.
+pub fn is_iso_date(s: &str) -> bool {
+    let mut parts = s.split('-');
+    let (Some(y), Some(m), Some(d), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    let number = |p: &str, len: usize| -> Option<u32> {
+        (p.len() == len && p.chars().all(|c| c.is_ascii_digit()))
+            .then(|| p.parse().ok())
+            .flatten()
+    };
+    matches!(
+        (number(y, 4), number(m, 2), number(d, 2)),
+        (Some(_), Some(1..=12), Some(1..=31))
+    )
+}
+
+/// An ecosystem's fields, borrowed from whichever struct holds them.
+pub struct EcosystemFields<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub short: &'a str,
+    pub languages: &'a [String],
+    pub vm: &'a str,
+    pub transaction_model: &'a str,
+    pub evm_compatibility: &'a str,
+    pub deployment_options: &'a [String],
+    pub chain_layer: &'a str,
+    pub consensus: &'a str,
+    /// `(field, value)` for each 1–5 rating.
+    pub ratings: [(&'static str, u8); 4],
+    pub tooling: &'a [String],
+    /// `(mode, languages)` for each entry in `deploy_modes`.
+    pub deploy_modes: Vec<(&'a str, &'a [String])>,
+    /// `(field, source, last_verified)` for each provenance entry.
+    pub provenance: Vec<(&'a str, &'a str, &'a str)>,
+}
+
+/// Everything wrong with one ecosystem, as messages naming the field.
+/// `is_known_tool` says whether a tool is in `tool_categories.toml`.
+pub fn field_errors(f: &EcosystemFields, is_known_tool: impl Fn(&str) -> bool) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if f.id.is_empty()
+        || !f
+            .id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        errors.push(format!(
+            "id {:?} must be non-empty lowercase letters, digits and dashes",
+            f.id
+        ));
+    }
+
+    let required = [
+        ("name", f.name),
+        ("short", f.short),
+        ("vm", f.vm),
+        ("transaction_model", f.transaction_model),
+        ("chain_layer", f.chain_layer),
+        ("consensus", f.consensus),
+    ];
+    for (field, value) in required {
+        if value.trim().is_empty() {
+            errors.push(format!("{} is required", field));
+        }
+    }
+
+    if !EVM_COMPATIBILITY.contains(&f.evm_compatibility) {
+        errors.push(format!(
+            "evm_compatibility {:?} must be one of {}",
+            f.evm_compatibility,
+            EVM_COMPATIBILITY.join(", ")
+        ));
+    }
+
+    if f.languages.is_empty() {
+        errors.push("languages needs at least one language".to_string());
+    }
+    if f.deployment_options.is_empty() {
+        errors.push("deployment_options needs at least one option".to_string());
+    }
+
+    for (field, value) in f.ratings {
+        if !(1..=5).contains(&value) {
+            errors.push(format!("{} {} must be between 1 and 5", field, value));
+        }
+    }
+
+    for tool in f.tooling {
+        if !is_known_tool(tool) {
+            errors.push(format!("tooling {:?} isn't in tool_categories.toml", tool));
+        }
+    }
+
+    for (mode, languages) in &f.deploy_modes {
+        if languages.is_empty() {
+            errors.push(format!("deploy_modes.{} needs at least one language", mode));
+        }
+    }
+
+    for (field, source, last_verified) in &f.provenance {
+        if *field != "default" && !PROVENANCE_FIELDS.contains(field) {
+            errors.push(format!(
+                "provenance.{} isn't default or one of {}",
+                field,
+                PROVENANCE_FIELDS.join(", ")
+            ));
+        }
+        if !source.starts_with("https://") && !source.starts_with("http://") {
+            errors.push(format!("provenance.{}.source must be a URL", field));
+        }
+        if !is_iso_date(last_verified) {
+            errors.push(format!(
+                "provenance.{}.last_verified {:?} must be YYYY-MM-DD",
+                field, last_verified
+            ));
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,700 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::findings::{Finding, FindingKind, Severity};
+use crate::data::mitigations::mitigations_for;
+use crate::data::overrides::{find_override, PairOverride};
+
+/// One of the scored migration dimensions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dimension {
+    Language,
+    Vm,
+    TxModel,
+    EvmCompat,
+    Deploy,
+    Tooling,
+    Docs,
+    L2,
+    Funding,
+}
+
+impl Dimension {
+    pub const ALL: [Dimension; 9] = [
+        Dimension::Language,
+        Dimension::Vm,
+        Dimension::TxModel,
+        Dimension::EvmCompat,
+        Dimension::Deploy,
+        Dimension::Tooling,
+        Dimension::Docs,
+        Dimension::L2,
+        Dimension::Funding,
+    ];
+
+    /// Stable key used in data files and exports.
+    pub fn key(self) -> &'static str {
+        match self {
+            Dimension::Language => "language",
+            Dimension::Vm => "vm",
+            Dimension::TxModel => "tx_model",
+            Dimension::EvmCompat => "evm_compat",
+            Dimension::Deploy => "deploy",
+            Dimension::Tooling => "tooling",
+            Dimension::Docs => "docs",
+            Dimension::L2 => "l2",
+            Dimension::Funding => "funding",
+        }
+    }
+
+    /// Display name shown in the migration panel.
+    pub fn name(self) -> &'static str {
+        match self {
+            Dimension::Language => "Language",
+            Dimension::Vm => "VM / Runtime",
+            Dimension::TxModel => "Tx Model",
+            Dimension::EvmCompat => "EVM Compat",
+            Dimension::Deploy => "Deploy Model",
+            Dimension::Tooling => "Dest. Tooling",
+            Dimension::Docs => "Dest. Docs",
+            Dimension::L2 => "L2 Gap",
+            Dimension::Funding => "Ecosystem Funding",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize)]
+pub struct DimensionScore {
+    pub dimension: Dimension,
+    pub name: String,
+    pub score: f64,
+    pub label: String,
+    /// Set when a curated pair override adjusted this score.
+    pub overridden: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize)]
+pub struct MigrationReport {
+    pub overall: f64,
+    pub difficulty_label: String,
+    pub dimensions: Vec<DimensionScore>,
+    pub challenges: Vec<Finding>,
+    pub positives: Vec<Finding>,
+    pub pair_override: Option<&'static PairOverride>,
+}
+
+impl MigrationReport {
+    /// Score for a single dimension, 0.0 if the report doesn't include it.
+    pub fn score(&self, dim: Dimension) -> f64 {
+        self.dimensions
+            .iter()
+            .find(|d| d.dimension == dim)
+            .map_or(0.0, |d| d.score)
+    }
+}
+
+const W_LANGUAGE: f64 = 0.35;
+const W_VM: f64 = 0.12;
+const W_TX_MODEL: f64 = 0.08;
+const W_EVM_COMPAT: f64 = 0.08;
+const W_DEPLOY: f64 = 0.07;
+const W_TOOLING: f64 = 0.10;
+const W_DOCS: f64 = 0.07;
+const W_L2: f64 = 0.07;
+const W_FUNDING: f64 = 0.06;
+
+/// Relative importance of each dimension in the overall score. Weights are
+/// normalized when scoring, so they don't need to sum to 1.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Weights {
+    pub language: f64,
+    pub vm: f64,
+    pub tx_model: f64,
+    pub evm_compat: f64,
+    pub deploy: f64,
+    pub tooling: f64,
+    pub docs: f64,
+    pub l2: f64,
+    pub funding: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            language: W_LANGUAGE,
+            vm: W_VM,
+            tx_model: W_TX_MODEL,
+            evm_compat: W_EVM_COMPAT,
+            deploy: W_DEPLOY,
+            tooling: W_TOOLING,
+            docs: W_DOCS,
+            l2: W_L2,
+            funding: W_FUNDING,
+        }
+    }
+}
+
+/// Named weight presets, `default` first.
+pub const WEIGHT_PROFILES: [&str; 5] = ["default", "defi", "appchain", "skills", "grants"];
+
+impl Weights {
+    pub fn get(&self, dim: Dimension) -> f64 {
+        match dim {
+            Dimension::Language => self.language,
+            Dimension::Vm => self.vm,
+            Dimension::TxModel => self.tx_model,
+            Dimension::EvmCompat => self.evm_compat,
+            Dimension::Deploy => self.deploy,
+            Dimension::Tooling => self.tooling,
+            Dimension::Docs => self.docs,
+            Dimension::L2 => self.l2,
+            Dimension::Funding => self.funding,
+        }
+    }
+
+    pub fn set(&mut self, dim: Dimension, value: f64) {
+        let slot = match dim {
+            Dimension::Language => &mut self.language,
+            Dimension::Vm => &mut self.vm,
+            Dimension::TxModel => &mut self.tx_model,
+            Dimension::EvmCompat => &mut self.evm_compat,
+            Dimension::Deploy => &mut self.deploy,
+            Dimension::Tooling => &mut self.tooling,
+            Dimension::Docs => &mut self.docs,
+            Dimension::L2 => &mut self.l2,
+            Dimension::Funding => &mut self.funding,
+        };
+        *slot = value.max(0.0);
+    }
+
+    /// Look up a named preset from `WEIGHT_PROFILES`.
+    pub fn profile(name: &str) -> Option<Weights> {
+        let w = |values: [f64; 9]| {
+            let mut weights = Weights::default();
+            for (dim, v) in Dimension::ALL.iter().zip(values) {
+                weights.set(*dim, v);
+            }
+            weights
+        };
+        match name {
+            "default" => Some(Weights::default()),
+            // EVM compatibility, tooling and L2 liquidity dominate DeFi moves.
+            "defi" => Some(w([0.25, 0.10, 0.08, 0.15, 0.05, 0.15, 0.07, 0.10, 0.05])),
+            // Launching a chain: the deployment model matters most.
+            "appchain" => Some(w([0.28, 0.12, 0.05, 0.05, 0.22, 0.10, 0.08, 0.05, 0.05])),
+            // A team's existing skills: language and runtime dominate.
+            "skills" => Some(w([0.45, 0.15, 0.10, 0.04, 0.03, 0.10, 0.10, 0.01, 0.02])),
+            // Grant-driven moves weigh destination funding heavily.
+            "grants" => Some(w([0.25, 0.08, 0.06, 0.06, 0.05, 0.10, 0.08, 0.04, 0.28])),
+            _ => None,
+        }
+    }
+
+    /// The preset these weights match exactly, if any.
+    pub fn profile_name(&self) -> Option<&'static str> {
+        WEIGHT_PROFILES
+            .iter()
+            .copied()
+            .find(|name| Weights::profile(name).as_ref() == Some(self))
+    }
+
+    fn total(&self) -> f64 {
+        Dimension::ALL.iter().map(|&d| self.get(d)).sum()
+    }
+}
+
+/// Get the effective languages for an ecosystem given an optional deploy mode.
+pub fn effective_languages<'a>(eco: &'a Ecosystem, mode: Option<&str>) -> &'a [String] {
+    if let (Some(mode_name), Some(modes)) = (mode, &eco.deploy_modes) {
+        if let Some(dm) = modes.get(mode_name) {
+            return &dm.languages;
+        }
+    }
+    &eco.languages
+}
+
+/// Map a language string to its family for fuzzy matching.
+fn lang_family(lang: &str) -> &str {
+    match lang {
+        "Solidity" | "Vyper" | "Huff" | "Cairo" => "evm-adjacent",
+        "Rust" => "rust",
+        "Go" | "Golang" => "go",
+        "C++" | "C" => "c-family",
+        "TypeScript" | "JavaScript" | "AssemblyScript" => "js-family",
+        "Move" | "Sui Move" | "Aptos Move" => "move",
+        "Tolk" | "Tact" | "FunC" | "FunC (legacy)" => "ton-native",
+        "Aiken" | "Plinth" | "OpShin" => "cardano-native",
+        "Compact" => "zk-native",
+        _ => "other",
+    }
+}
+
+fn language_distance(
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+) -> f64 {
+    let src_langs = effective_languages(src, src_mode);
+    let dst_langs = effective_languages(dst, dst_mode);
+
+    let shared = src_langs
+        .iter()
+        .any(|l| dst_langs.iter().any(|d| d == l));
+    if shared {
+        return 0.0;
+    }
+
+    let src_families: Vec<&str> = src_langs.iter().map(|l| lang_family(l)).collect();
+    let dst_families: Vec<&str> = dst_langs.iter().map(|l| lang_family(l)).collect();
+
+    let shared_family = src_families.iter().any(|f| dst_families.contains(f));
+    if shared_family {
+        0.3
+    } else {
+        1.0
+    }
+}
+
+/// Categorize a VM string into a compatibility group.
+pub fn vm_group(vm: &str) -> &str {
+    match vm {
+        "EVM" | "EVM / Subnet-EVM" | "zkEVM" => "evm",
+        "PolkaVM/EVM" => "evm-plus-pvm",
+        "CosmWasm" | "NearVM" | "Soroban (Wasmi)" => "wasm",
+        "SVM (sBPF)" => "svm",
+        "Sui MoveVM" | "MoveVM + Block-STM" => "move",
+        "TVM" => "tvm",
+        "CairoVM (STARK)" => "cairo",
+        "Plutus VM (UPLC)" => "plutus",
+        "ZK Circuit VM" => "zk-circuit",
+        "N/A (DA layer)" => "da-layer",
+        "XRPL Native" => "xrpl-native",
+        _ => "other",
+    }
+}
+
+fn vm_distance(src: &Ecosystem, dst: &Ecosystem) -> f64 {
+    if src.vm == dst.vm {
+        return 0.0;
+    }
+
+    let sg = vm_group(&src.vm);
+    let dg = vm_group(&dst.vm);
+
+    if sg == dg {
+        return 0.2;
+    }
+
+    // PolkaVM/EVM overlaps with both EVM and Wasm groups
+    if (sg == "evm-plus-pvm" && (dg == "evm" || dg == "wasm"))
+        || (dg == "evm-plus-pvm" && (sg == "evm" || sg == "wasm"))
+    {
+        return 0.3;
+    }
+
+    // DA layer has no VM — always a big gap
+    if sg == "da-layer" || dg == "da-layer" {
+        return 1.0;
+    }
+
+    // XRPL has no general-purpose VM — always a big gap
+    if sg == "xrpl-native" || dg == "xrpl-native" {
+        return 1.0;
+    }
+
+    1.0
+}
+
+fn transaction_model_distance(src: &Ecosystem, dst: &Ecosystem) -> f64 {
+    if src.transaction_model == dst.transaction_model {
+        return 0.0;
+    }
+
+    let is_account_family =
+        |m: &str| matches!(m, "account" | "account-resource");
+
+    // Account variants are close
+    if is_account_family(&src.transaction_model) && is_account_family(&dst.transaction_model) {
+        return 0.15;
+    }
+
+    // eUTXO ↔ account is a moderate paradigm shift
+    if (is_account_family(&src.transaction_model) && dst.transaction_model == "eUTXO")
+        || (src.transaction_model == "eUTXO" && is_account_family(&dst.transaction_model))
+    {
+        return 0.6;
+    }
+
+    // eUTXO variants are close
+    if src.transaction_model == "eUTXO" && dst.transaction_model == "eUTXO" {
+        return 0.0;
+    }
+
+    // Object-centric ↔ account is moderate
+    if (is_account_family(&src.transaction_model) && dst.transaction_model == "object-centric")
+        || (src.transaction_model == "object-centric" && is_account_family(&dst.transaction_model))
+    {
+        return 0.5;
+    }
+
+    // Actor ↔ anything else is significant
+    if src.transaction_model == "actor" || dst.transaction_model == "actor" {
+        return 0.8;
+    }
+
+    0.7
+}
+
+/// EVM compatibility distance: moving between EVM-native chains is trivial,
+/// moving from EVM to non-EVM (or vice versa) is a penalty.
+fn evm_compat_distance(src: &Ecosystem, dst: &Ecosystem) -> f64 {
+    let rank = |c: &str| -> u8 {
+        match c {
+            "native" => 0,
+            "supported" => 1,
+            _ => 2, // "none"
+        }
+    };
+
+    let sr = rank(&src.evm_compatibility);
+    let dr = rank(&dst.evm_compatibility);
+
+    if sr == dr {
+        return 0.0;
+    }
+
+    // native ↔ supported is a small gap
+    if sr <= 1 && dr <= 1 {
+        return 0.2;
+    }
+
+    // EVM world ↔ non-EVM world
+    if (sr <= 1 && dr == 2) || (sr == 2 && dr <= 1) {
+        return 1.0;
+    }
+
+    0.5
+}
+
+/// Deployment model distance: measures how different the "what you ship" is.
+fn deploy_model_distance(src: &Ecosystem, dst: &Ecosystem) -> f64 {
+    let src_set: Vec<&str> = src.deployment_options.iter().map(|s| s.as_str()).collect();
+    let dst_set: Vec<&str> = dst.deployment_options.iter().map(|s| s.as_str()).collect();
+
+    // Any shared deployment option = familiar territory
+    let shared = src_set.iter().any(|s| dst_set.contains(s));
+    if shared {
+        return 0.0;
+    }
+
+    let has = |set: &[&str], opt: &str| set.contains(&opt);
+
+    // Sidechains are penalized heavily: separate validator set, bridge
+    // trust assumptions, not a native solution. Worse than appchain/rollup
+    // transitions (0.7) since L2s and appchains inherit or extend L1 security.
+    if (has(&src_set, "sidechain") && has(&dst_set, "contract"))
+        || (has(&src_set, "contract") && has(&dst_set, "sidechain"))
+    {
+        return 0.8;
+    }
+
+    if has(&src_set, "sidechain") || has(&dst_set, "sidechain") {
+        return 0.9;
+    }
+
+    // contract-only ↔ appchain-only is a big shift
+    // contract ↔ rollup is moderate
+    if (has(&src_set, "contract") && has(&dst_set, "appchain") && !has(&dst_set, "contract"))
+        || (has(&src_set, "appchain") && has(&dst_set, "contract") && !has(&dst_set, "appchain"))
+    {
+        return 0.7;
+    }
+
+    0.5
+}
+
+fn dest_tooling_difficulty(dst: &Ecosystem) -> f64 {
+    1.0 - (dst.tooling_maturity as f64 - 1.0) / 4.0
+}
+
+fn dest_docs_difficulty(dst: &Ecosystem) -> f64 {
+    1.0 - (dst.doc_quality as f64 - 1.0) / 4.0
+}
+
+fn dest_funding_difficulty(dst: &Ecosystem) -> f64 {
+    1.0 - (dst.ecosystem_funding as f64 - 1.0) / 4.0
+}
+
+fn l2_gap(src: &Ecosystem, dst: &Ecosystem) -> f64 {
+    let diff = (src.l2_maturity as f64 - dst.l2_maturity as f64).abs();
+    diff / 4.0
+}
+
+/// Overall difficulty bands, easiest first. The ring view draws one ring per band.
+pub const DIFFICULTY_LABELS: [&str; 5] = ["Trivial", "Easy", "Moderate", "Hard", "Extreme"];
+
+/// Index into `DIFFICULTY_LABELS` for an overall score.
+pub fn difficulty_band(score: f64) -> usize {
+    match score {
+        x if x < 0.2 => 0,
+        x if x < 0.4 => 1,
+        x if x < 0.6 => 2,
+        x if x < 0.8 => 3,
+        _ => 4,
+    }
+}
+
+fn score_label(s: f64) -> String {
+    DIFFICULTY_LABELS[difficulty_band(s)].to_string()
+}
+
+fn dim_label(s: f64) -> String {
+    match s {
+        x if x < 0.15 => "Same".to_string(),
+        x if x < 0.35 => "Similar".to_string(),
+        x if x < 0.65 => "Different".to_string(),
+        _ => "Very different".to_string(),
+    }
+}
+
+fn funding_label(s: f64) -> String {
+    match s {
+        x if x < 0.15 => "Strong opportunities".to_string(),
+        x if x < 0.35 => "Good opportunities".to_string(),
+        x if x < 0.65 => "Moderate opportunities".to_string(),
+        _ => "Limited opportunities".to_string(),
+    }
+}
+
+pub fn compute_migration(
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+) -> MigrationReport {
+    compute_migration_weighted(src, dst, src_mode, dst_mode, &Weights::default())
+}
+
+/// `compute_migration` with custom dimension weights.
+pub fn compute_migration_weighted(
+    src: &Ecosystem,
+    dst: &Ecosystem,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+    weights: &Weights,
+) -> MigrationReport {
+    let pair_override = find_override(&src.id, &dst.id, src_mode, dst_mode);
+
+    // Curated overrides are applied on top of the generic score. Findings
+    // below follow the adjusted values, except the same-language and
+    // same-VM positives, which use the raw distances.
+    let adjusted = |dim: Dimension, raw: f64| -> (f64, bool) {
+        match pair_override.map(|ov| ov.adjustment(dim)) {
+            Some(delta) if delta != 0.0 => ((raw + delta).clamp(0.0, 1.0), true),
+            _ => (raw, false),
+        }
+    };
+
+    // Positives about shared languages and VMs describe the ecosystems
+    // themselves, so they use the scores before overrides.
+    let raw_lang = language_distance(src, dst, src_mode, dst_mode);
+    let raw_vm = vm_distance(src, dst);
+
+    let scores = [
+        adjusted(Dimension::Language, raw_lang),
+        adjusted(Dimension::Vm, raw_vm),
+        adjusted(Dimension::TxModel, transaction_model_distance(src, dst)),
+        adjusted(Dimension::EvmCompat, evm_compat_distance(src, dst)),
+        adjusted(Dimension::Deploy, deploy_model_distance(src, dst)),
+        adjusted(Dimension::Tooling, dest_tooling_difficulty(dst)),
+        adjusted(Dimension::Docs, dest_docs_difficulty(dst)),
+        adjusted(Dimension::L2, l2_gap(src, dst)),
+        adjusted(Dimension::Funding, dest_funding_difficulty(dst)),
+    ];
+
+    let dimensions: Vec<DimensionScore> = Dimension::ALL
+        .iter()
+        .zip(scores)
+        .map(|(&dimension, (score, overridden))| DimensionScore {
+            dimension,
+            name: dimension.name().to_string(),
+            score,
+            label: if dimension == Dimension::Funding {
+                funding_label(score)
+            } else {
+                dim_label(score)
+            },
+            overridden,
+        })
+        .collect();
+
+    let total_weight = weights.total();
+    let overall: f64 = if total_weight > 0.0 {
+        dimensions
+            .iter()
+            .map(|d| d.score * weights.get(d.dimension))
+            .sum::<f64>()
+            / total_weight
+    } else {
+        0.0
+    };
+
+    let [lang, vm, state, evm, deploy, tooling, docs, l2, _funding] = scores.map(|(s, _)| s);
+
+    let src_langs = effective_languages(src, src_mode);
+    let dst_langs = effective_languages(dst, dst_mode);
+
+    // ── Positives ──
+    let mut positives = Vec::new();
+    let positive = |kind, dim| Finding::new(kind, Severity::Info, Some(dim));
+
+    if raw_lang == 0.0 {
+        positives.push(
+            positive(FindingKind::SameLanguage, Dimension::Language).between(src_langs, dst_langs),
+        );
+    } else if raw_lang <= 0.3 {
+        positives.push(positive(FindingKind::RelatedLanguageFamily, Dimension::Language));
+    }
+
+    if raw_vm == 0.0 {
+        positives.push(
+            positive(FindingKind::SameVm, Dimension::Vm)
+                .between(&[&src.vm], &[&dst.vm]),
+        );
+    }
+
+    if evm == 0.0 && src.evm_compatibility == "native" {
+        positives.push(positive(FindingKind::BothEvmNative, Dimension::EvmCompat));
+    }
+
+    if state == 0.0 {
+        positives.push(positive(FindingKind::SameTxModel, Dimension::TxModel));
+    }
+
+    if deploy == 0.0 {
+        positives.push(positive(FindingKind::SameDeployModel, Dimension::Deploy));
+    }
+
+    if tooling <= 0.25 {
+        positives.push(
+            positive(FindingKind::ExcellentTooling, Dimension::Tooling).rating(dst.tooling_maturity),
+        );
+    }
+
+    if docs <= 0.25 {
+        positives.push(positive(FindingKind::StrongDocs, Dimension::Docs).rating(dst.doc_quality));
+    }
+
+    if dst.ecosystem_funding >= 4 {
+        positives.push(
+            positive(FindingKind::WellFunded, Dimension::Funding).rating(dst.ecosystem_funding),
+        );
+    }
+
+    // ── Challenges ──
+    let mut challenges = Vec::new();
+
+    if lang >= 0.8 {
+        challenges.push(
+            Finding::new(FindingKind::LanguageChange, Severity::Critical, Some(Dimension::Language))
+                .between(src_langs, dst_langs),
+        );
+    } else if lang >= 0.3 {
+        challenges.push(
+            Finding::new(
+                FindingKind::RelatedLanguageChange,
+                Severity::Minor,
+                Some(Dimension::Language),
+            )
+            .between(src_langs, dst_langs),
+        );
+    }
+
+    if vm >= 0.8 {
+        challenges.push(
+            Finding::new(FindingKind::VmChange, Severity::Major, Some(Dimension::Vm))
+                .between(&[&src.vm], &[&dst.vm]),
+        );
+    }
+
+    if state >= 0.5 {
+        challenges.push(
+            Finding::new(FindingKind::TxModelChange, Severity::Major, Some(Dimension::TxModel))
+                .between(&[&src.transaction_model], &[&dst.transaction_model]),
+        );
+    }
+
+    if evm >= 0.8 {
+        let kind = if src.evm_compatibility == "native" || src.evm_compatibility == "supported" {
+            FindingKind::LeavingEvm
+        } else {
+            FindingKind::EnteringEvm
+        };
+        challenges.push(Finding::new(kind, Severity::Major, Some(Dimension::EvmCompat)));
+    }
+
+    if deploy >= 0.5 {
+        challenges.push(
+            Finding::new(
+                FindingKind::DeployModelChange,
+                Severity::Minor,
+                Some(Dimension::Deploy),
+            )
+            .between(&src.deployment_options, &dst.deployment_options),
+        );
+    }
+
+    if tooling >= 0.6 {
+        challenges.push(
+            Finding::new(FindingKind::ImmatureTooling, Severity::Minor, Some(Dimension::Tooling))
+                .rating(dst.tooling_maturity),
+        );
+    }
+
+    if docs >= 0.6 {
+        challenges.push(
+            Finding::new(FindingKind::LimitedDocs, Severity::Minor, Some(Dimension::Docs))
+                .rating(dst.doc_quality),
+        );
+    }
+
+    if l2 >= 0.5 {
+        challenges.push(Finding::new(FindingKind::L2Gap, Severity::Minor, Some(Dimension::L2)));
+    }
+
+    if dst.ecosystem_funding <= 2 {
+        challenges.push(
+            Finding::new(FindingKind::LimitedFunding, Severity::Minor, Some(Dimension::Funding))
+                .rating(dst.ecosystem_funding),
+        );
+    }
+
+    if let Some(ov) = pair_override {
+        positives.extend(
+            ov.positives
+                .iter()
+                .map(|p| Finding::new(FindingKind::Curated, Severity::Info, None).note(p)),
+        );
+        challenges.extend(
+            ov.challenges
+                .iter()
+                .map(|c| Finding::new(FindingKind::Curated, Severity::Minor, None).note(c)),
+        );
+    }
+
+    for challenge in &mut challenges {
+        challenge.mitigations = mitigations_for(challenge.kind, src, dst, dst_mode);
+    }
+
+    MigrationReport {
+        overall,
+        difficulty_label: score_label(overall),
+        dimensions,
+        challenges,
+        positives,
+        pair_override,
+    }
+}
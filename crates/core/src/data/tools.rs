@@ -0,0 +1,12 @@
+/// A group of interchangeable tools, loaded from `data/tool_categories.toml`.
+pub struct ToolCategory {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub tools: &'static [&'static str],
+}
+
+include!(concat!(env!("OUT_DIR"), "/tool_categories_generated.rs"));
+
+pub fn tool_category(tool: &str) -> Option<&'static ToolCategory> {
+    TOOL_CATEGORIES.iter().find(|c| c.tools.contains(&tool))
+}
@@ -0,0 +1,181 @@
+//! Differences between two versions of the dataset: field changes per
+//! ecosystem and the migration verdicts they move.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::{compute_migration_weighted, Weights};
+
+#[derive(Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    /// `null` when the field was unset.
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Serialize)]
+pub struct EcosystemChanges {
+    pub id: String,
+    pub fields: Vec<FieldChange>,
+}
+
+/// A pair whose overall score or difficulty label moved, scored with the
+/// default deploy modes.
+#[derive(Serialize)]
+pub struct PairChange {
+    pub source: String,
+    pub dest: String,
+    pub before: f64,
+    pub after: f64,
+    pub before_label: String,
+    pub after_label: String,
+}
+
+impl PairChange {
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+#[derive(Serialize)]
+pub struct DatasetDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<EcosystemChanges>,
+    /// Largest score change first.
+    pub pairs: Vec<PairChange>,
+}
+
+fn field_changes(before: &Ecosystem, after: &Ecosystem) -> Vec<FieldChange> {
+    let (Ok(Value::Object(before)), Ok(Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let b = before.get(field).cloned().unwrap_or(Value::Null);
+            let a = after.get(field).cloned().unwrap_or(Value::Null);
+            (b != a).then(|| FieldChange {
+                field: field.clone(),
+                before: b,
+                after: a,
+            })
+        })
+        .collect()
+}
+
+/// Compare two datasets. Pairs are only compared between ecosystems present
+/// in both.
+pub fn diff_datasets(old: &[Ecosystem], new: &[Ecosystem], weights: &Weights) -> DatasetDiff {
+    let find = |ecos: &[Ecosystem], id: &str| ecos.iter().position(|e| e.id == id);
+
+    let added = new
+        .iter()
+        .filter(|e| find(old, &e.id).is_none())
+        .map(|e| e.id.clone())
+        .collect();
+    let removed = old
+        .iter()
+        .filter(|e| find(new, &e.id).is_none())
+        .map(|e| e.id.clone())
+        .collect();
+
+    let common: Vec<(&Ecosystem, &Ecosystem)> = new
+        .iter()
+        .filter_map(|e| find(old, &e.id).map(|i| (&old[i], e)))
+        .collect();
+
+    let changed = common
+        .iter()
+        .filter_map(|(before, after)| {
+            let fields = field_changes(before, after);
+            (!fields.is_empty()).then(|| EcosystemChanges {
+                id: after.id.clone(),
+                fields,
+            })
+        })
+        .collect();
+
+    let mut pairs = Vec::new();
+    for (src_old, src_new) in &common {
+        for (dst_old, dst_new) in &common {
+            if src_new.id == dst_new.id {
+                continue;
+            }
+            let before = compute_migration_weighted(src_old, dst_old, None, None, weights);
+            let after = compute_migration_weighted(src_new, dst_new, None, None, weights);
+            if (after.overall - before.overall).abs() > 1e-9
+                || after.difficulty_label != before.difficulty_label
+            {
+                pairs.push(PairChange {
+                    source: src_new.id.clone(),
+                    dest: dst_new.id.clone(),
+                    before: before.overall,
+                    after: after.overall,
+                    before_label: before.difficulty_label,
+                    after_label: after.difficulty_label,
+                });
+            }
+        }
+    }
+    pairs.sort_by(|a, b| {
+        b.delta()
+            .abs()
+            .total_cmp(&a.delta().abs())
+            .then_with(|| (&a.source, &a.dest).cmp(&(&b.source, &b.dest)))
+    });
+
+    DatasetDiff {
+        added,
+        removed,
+        changed,
+        pairs,
+    }
+}
+
+/// A plain-text summary, scores out of 5 as in the app.
+pub fn diff_text(diff: &DatasetDiff) -> String {
+    let mut out = String::new();
+    if !diff.added.is_empty() {
+        out.push_str(&format!("Added: {}\n", diff.added.join(", ")));
+    }
+    if !diff.removed.is_empty() {
+        out.push_str(&format!("Removed: {}\n", diff.removed.join(", ")));
+    }
+    for eco in &diff.changed {
+        out.push_str(&format!("\n{}\n", eco.id));
+        for f in &eco.fields {
+            out.push_str(&format!("  {}: {} → {}\n", f.field, f.before, f.after));
+        }
+    }
+
+    if diff.pairs.is_empty() {
+        out.push_str("\nNo migration verdicts changed.\n");
+    } else {
+        out.push_str(&format!("\nChanged pairs ({}):\n", diff.pairs.len()));
+        for p in &diff.pairs {
+            let label = if p.before_label == p.after_label {
+                p.after_label.clone()
+            } else {
+                format!("{} → {}", p.before_label, p.after_label)
+            };
+            out.push_str(&format!(
+                "  {:+.2}  {} → {}: {:.2} → {:.2}/5 ({})\n",
+                p.delta() * 5.0,
+                p.source,
+                p.dest,
+                p.before * 5.0,
+                p.after * 5.0,
+                label
+            ));
+        }
+    }
+    out
+}
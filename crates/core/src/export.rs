@@ -0,0 +1,294 @@
+//! Text exports of migration analyses: Markdown and JSON reports, CSV
+//! matrices, and Markdown issues for migration plans.
+
+use serde::Serialize;
+
+use crate::data::dataset::DATASET_VERSION;
+use crate::data::ecosystem::Ecosystem;
+use crate::data::effort::{estimate_effort, EffortEstimate, ProjectProfile};
+use crate::data::plan::MigrationPlan;
+use crate::data::scoring::{compute_migration_weighted, Dimension, MigrationReport, Weights};
+use crate::text::{
+    finding_text, mitigation_category_label, plan_section_name, plan_step_detail, plan_step_title,
+};
+
+/// Everything that went into a report, recorded alongside it in exports.
+#[derive(Clone, Serialize)]
+pub struct ReportInputs {
+    pub source: Ecosystem,
+    pub dest: Ecosystem,
+    pub src_mode: Option<String>,
+    pub dst_mode: Option<String>,
+    pub project: ProjectProfile,
+    pub weights: Weights,
+    /// The team's own notes on this comparison.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// A migration report bundled with its inputs and effort estimate.
+#[derive(Clone, Serialize)]
+pub struct ReportExport {
+    pub dataset_version: &'static str,
+    /// Ids of the compared ecosystems whose data differs from the bundled
+    /// dataset: imported, overlaid, custom or edited in the what-if panel.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub modified: Vec<String>,
+    pub inputs: ReportInputs,
+    pub report: MigrationReport,
+    pub effort: EffortEstimate,
+}
+
+impl ReportExport {
+    /// Score `inputs`, noting which of the two ecosystems differ from
+    /// `bundled`.
+    pub fn new(inputs: ReportInputs, bundled: &[Ecosystem]) -> Self {
+        let report = compute_migration_weighted(
+            &inputs.source,
+            &inputs.dest,
+            inputs.src_mode.as_deref(),
+            inputs.dst_mode.as_deref(),
+            &inputs.weights,
+        );
+        let effort = estimate_effort(&report, &inputs.project);
+        let mut modified: Vec<String> = [&inputs.source, &inputs.dest]
+            .into_iter()
+            .filter(|eco| !bundled.contains(eco))
+            .map(|eco| eco.id.clone())
+            .collect();
+        modified.dedup();
+        ReportExport {
+            dataset_version: DATASET_VERSION,
+            modified,
+            inputs,
+            report,
+            effort,
+        }
+    }
+
+    /// The dataset version, noting any compared ecosystem that doesn't
+    /// match the bundled data.
+    pub fn dataset_label(&self) -> String {
+        if self.modified.is_empty() {
+            format!("Dataset version {}", self.dataset_version)
+        } else {
+            format!(
+                "Dataset version {}, modified: {}",
+                self.dataset_version,
+                self.modified.join(", ")
+            )
+        }
+    }
+}
+
+fn route_label(eco: &Ecosystem, mode: Option<&str>) -> String {
+    match mode {
+        Some(m) => format!("{} ({})", eco.name, m),
+        None => eco.name.clone(),
+    }
+}
+
+pub fn report_markdown(export: &ReportExport) -> String {
+    let inputs = &export.inputs;
+    let r = &export.report;
+    let mut out = format!(
+        "# Migration: {} → {}\n\n",
+        route_label(&inputs.source, inputs.src_mode.as_deref()),
+        route_label(&inputs.dest, inputs.dst_mode.as_deref())
+    );
+
+    out.push_str(&format!(
+        "**Overall difficulty:** {:.1}/5 ({})\n\n",
+        r.overall * 5.0,
+        r.difficulty_label
+    ));
+
+    if inputs.weights != Weights::default() {
+        let weights: Vec<String> = Dimension::ALL
+            .iter()
+            .map(|&d| format!("{} {:.2}", d.name(), inputs.weights.get(d)))
+            .collect();
+        out.push_str(&format!("**Custom weights:** {}\n\n", weights.join(", ")));
+    }
+
+    out.push_str("## Dimensions\n\n| Dimension | Score | Assessment |\n|---|---|---|\n");
+    for d in &r.dimensions {
+        let mark = if d.overridden { " \\*" } else { "" };
+        out.push_str(&format!(
+            "| {}{} | {:.2} | {} |\n",
+            d.name, mark, d.score, d.label
+        ));
+    }
+    out.push('\n');
+
+    if let Some(ov) = r.pair_override {
+        out.push_str(&format!(
+            "\\* Curated override: {} ([source]({}))\n\n",
+            ov.justification, ov.source_url
+        ));
+    }
+
+    if !r.challenges.is_empty() {
+        out.push_str("## Key Challenges\n\n");
+        for c in &r.challenges {
+            out.push_str(&format!("- {}\n", finding_text(c)));
+            for m in &c.mitigations {
+                let link = m
+                    .url
+                    .map(|u| format!(" ([link]({}))", u))
+                    .unwrap_or_default();
+                out.push_str(&format!(
+                    "  - *{}:* {}{}\n",
+                    mitigation_category_label(m.category),
+                    m.text,
+                    link
+                ));
+            }
+        }
+        out.push('\n');
+    }
+
+    if !r.positives.is_empty() {
+        out.push_str("## Advantages\n\n");
+        for p in &r.positives {
+            out.push_str(&format!("- {}\n", finding_text(p)));
+        }
+        out.push('\n');
+    }
+
+    let project = &inputs.project;
+    let e = &export.effort;
+    out.push_str(&format!(
+        "## Effort Estimate\n\n{} contracts, {} LOC, team of {}, {:.0}% test coverage.\n\n",
+        project.contracts,
+        project.loc,
+        project.team_size,
+        project.test_coverage * 100.0
+    ));
+    out.push_str("| Phase | Person-weeks |\n|---|---|\n");
+    for p in &e.phases {
+        out.push_str(&format!(
+            "| {} | {:.0}–{:.0} |\n",
+            p.phase.name(),
+            p.low.ceil(),
+            p.high.ceil()
+        ));
+    }
+    out.push_str(&format!(
+        "| **Total** | **{:.0}–{:.0}** |\n\n",
+        e.low.ceil(),
+        e.high.ceil()
+    ));
+
+    if let Some(notes) = &inputs.notes {
+        out.push_str(&format!("## Notes\n\n{}\n\n", notes.trim()));
+    }
+
+    out.push_str(&format!("_{}_\n", export.dataset_label()));
+    out
+}
+
+pub fn report_json(export: &ReportExport) -> String {
+    serde_json::to_string_pretty(export).expect("serialize report")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Square matrix of overall scores: rows are sources, columns destinations.
+pub fn matrix_csv(ecosystems: &[Ecosystem], weights: &Weights) -> String {
+    let mut out = String::from("source");
+    for dst in ecosystems {
+        out.push(',');
+        out.push_str(&csv_field(&dst.id));
+    }
+    out.push('\n');
+
+    for src in ecosystems {
+        out.push_str(&csv_field(&src.id));
+        for dst in ecosystems {
+            out.push(',');
+            if src.id != dst.id {
+                let r = compute_migration_weighted(src, dst, None, None, weights);
+                out.push_str(&format!("{:.4}", r.overall));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// One row per ordered pair with every dimension score, for spreadsheets.
+pub fn pairs_csv(ecosystems: &[Ecosystem], weights: &Weights) -> String {
+    let mut out = String::from("source,dest,overall,difficulty");
+    for dim in Dimension::ALL {
+        out.push(',');
+        out.push_str(dim.key());
+    }
+    out.push('\n');
+
+    for src in ecosystems {
+        for dst in ecosystems.iter().filter(|d| d.id != src.id) {
+            let r = compute_migration_weighted(src, dst, None, None, weights);
+            out.push_str(&format!(
+                "{},{},{:.4},{}",
+                csv_field(&src.id),
+                csv_field(&dst.id),
+                r.overall,
+                r.difficulty_label
+            ));
+            for d in &r.dimensions {
+                out.push_str(&format!(",{:.4}", d.score));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Render a plan as Markdown issues, one `##` block per step, ready to paste
+/// into an issue tracker.
+pub fn plan_markdown_issues(plan: &MigrationPlan, src: &Ecosystem, dst: &Ecosystem) -> String {
+    let mut out = format!("# Migration plan: {} → {}\n\n", src.name, dst.name);
+
+    for (i, step) in plan.steps.iter().enumerate() {
+        out.push_str(&format!(
+            "## {}. {}\n\n{}\n\nLabels: `migration`, `{}`\n\n- [ ] Done\n\n",
+            i + 1,
+            plan_step_title(step),
+            plan_step_detail(step),
+            plan_section_name(step.kind.section()).to_lowercase()
+        ));
+    }
+
+    out
+}
+
+/// Percent-encode everything but unreserved characters, for `data:` URLs
+/// and query strings.
+pub fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// A `data:` URL for downloading text from an `<a download>` link.
+pub fn data_url(mime: &str, text: &str) -> String {
+    format!("data:{};charset=utf-8,{}", mime, percent_encode(text))
+}
+
+pub fn markdown_data_url(markdown: &str) -> String {
+    data_url("text/markdown", markdown)
+}
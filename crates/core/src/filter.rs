@@ -0,0 +1,278 @@
+//! Search and facet filters over the ecosystem list, with a compact URL query
+//! form so a filtered view can be linked, plus fuzzy matching for pickers.
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::vm_group;
+use crate::export::percent_encode;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Facet {
+    Language,
+    VmGroup,
+    TxModel,
+    EvmCompat,
+    ChainLayer,
+    Deploy,
+}
+
+impl Facet {
+    pub const ALL: [Facet; 6] = [
+        Facet::Language,
+        Facet::VmGroup,
+        Facet::TxModel,
+        Facet::EvmCompat,
+        Facet::ChainLayer,
+        Facet::Deploy,
+    ];
+
+    /// URL query key.
+    pub fn key(self) -> &'static str {
+        match self {
+            Facet::Language => "lang",
+            Facet::VmGroup => "vm",
+            Facet::TxModel => "tx",
+            Facet::EvmCompat => "evm",
+            Facet::ChainLayer => "layer",
+            Facet::Deploy => "deploy",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Facet::Language => "Language",
+            Facet::VmGroup => "VM group",
+            Facet::TxModel => "Transaction model",
+            Facet::EvmCompat => "EVM compatibility",
+            Facet::ChainLayer => "Chain layer",
+            Facet::Deploy => "Deployment",
+        }
+    }
+
+    /// The values an ecosystem has for this facet.
+    pub fn values(self, eco: &Ecosystem) -> Vec<&str> {
+        match self {
+            Facet::Language => eco.languages.iter().map(String::as_str).collect(),
+            Facet::VmGroup => vec![vm_group(&eco.vm)],
+            Facet::TxModel => vec![&eco.transaction_model],
+            Facet::EvmCompat => vec![&eco.evm_compatibility],
+            Facet::ChainLayer => vec![&eco.chain_layer],
+            Facet::Deploy => eco.deployment_options.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Every value present in the dataset, in first-seen order.
+    pub fn options(self, ecosystems: &[Ecosystem]) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for eco in ecosystems {
+            for v in self.values(eco) {
+                if !out.iter().any(|o| o == v) {
+                    out.push(v.to_string());
+                }
+            }
+        }
+        out
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Ratings that can be given a minimum.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinScore {
+    Tooling,
+    Docs,
+    Funding,
+}
+
+impl MinScore {
+    pub const ALL: [MinScore; 3] = [MinScore::Tooling, MinScore::Docs, MinScore::Funding];
+
+    /// URL query key.
+    pub fn key(self) -> &'static str {
+        match self {
+            MinScore::Tooling => "tooling",
+            MinScore::Docs => "docs",
+            MinScore::Funding => "funding",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MinScore::Tooling => "Tooling",
+            MinScore::Docs => "Docs",
+            MinScore::Funding => "Funding",
+        }
+    }
+
+    pub fn rating(self, eco: &Ecosystem) -> u8 {
+        match self {
+            MinScore::Tooling => eco.tooling_maturity,
+            MinScore::Docs => eco.doc_quality,
+            MinScore::Funding => eco.ecosystem_funding,
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Filter {
+    /// Free-text search; every word must appear in the ecosystem's name, id,
+    /// languages, VM, consensus or tooling.
+    pub query: String,
+    /// Selected values per facet, indexed like `Facet::ALL`. Values within a
+    /// facet are alternatives; an empty facet doesn't filter.
+    pub facets: [Vec<String>; 6],
+    /// Minimum ratings, indexed like `MinScore::ALL`; 0 doesn't filter.
+    pub min_scores: [u8; 3],
+}
+
+impl Filter {
+    pub fn is_active(&self) -> bool {
+        *self != Filter::default()
+    }
+
+    pub fn selected(&self, facet: Facet) -> &[String] {
+        &self.facets[facet.index()]
+    }
+
+    pub fn is_selected(&self, facet: Facet, value: &str) -> bool {
+        self.selected(facet).iter().any(|v| v == value)
+    }
+
+    pub fn toggle(&mut self, facet: Facet, value: &str) {
+        let values = &mut self.facets[facet.index()];
+        match values.iter().position(|v| v == value) {
+            Some(i) => {
+                values.remove(i);
+            }
+            None => values.push(value.to_string()),
+        }
+    }
+
+    pub fn matches(&self, eco: &Ecosystem) -> bool {
+        let haystack = [
+            eco.name.as_str(),
+            &eco.id,
+            &eco.short,
+            &eco.vm,
+            &eco.consensus,
+            &eco.languages.join(" "),
+            &eco.tooling.join(" "),
+        ]
+        .join(" ")
+        .to_lowercase();
+        let query_ok = self
+            .query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|word| haystack.contains(word));
+
+        let facets_ok = Facet::ALL.iter().all(|&facet| {
+            let selected = self.selected(facet);
+            selected.is_empty()
+                || facet
+                    .values(eco)
+                    .iter()
+                    .any(|v| selected.iter().any(|s| s == v))
+        });
+
+        let scores_ok = MinScore::ALL
+            .iter()
+            .zip(self.min_scores)
+            .all(|(score, min)| score.rating(eco) >= min);
+
+        query_ok && facets_ok && scores_ok
+    }
+
+    /// URL query form, e.g. `q=wasm&lang=Rust,Go&evm=none&tooling=3`, without
+    /// the leading `?`. Empty when nothing is filtered.
+    pub fn to_query(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.query.trim().is_empty() {
+            parts.push(format!("q={}", percent_encode(self.query.trim())));
+        }
+        for facet in Facet::ALL {
+            let selected = self.selected(facet);
+            if !selected.is_empty() {
+                let values: Vec<String> = selected.iter().map(|v| percent_encode(v)).collect();
+                parts.push(format!("{}={}", facet.key(), values.join(",")));
+            }
+        }
+        for (score, min) in MinScore::ALL.iter().zip(self.min_scores) {
+            if min > 0 {
+                parts.push(format!("{}={}", score.key(), min));
+            }
+        }
+        parts.join("&")
+    }
+
+    /// Parse `to_query` output, with or without a leading `?`. Unknown keys
+    /// are ignored so the filter can share the URL with other state.
+    pub fn from_query(query: &str) -> Filter {
+        let mut filter = Filter::default();
+        for pair in query.trim_start_matches('?').split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            if key == "q" {
+                filter.query = percent_decode(value);
+            } else if let Some(facet) = Facet::ALL.iter().find(|f| f.key() == key) {
+                filter.facets[facet.index()] = value
+                    .split(',')
+                    .filter(|v| !v.is_empty())
+                    .map(percent_decode)
+                    .collect();
+            } else if let Some(i) = MinScore::ALL.iter().position(|s| s.key() == key) {
+                filter.min_scores[i] = value.parse::<u8>().unwrap_or(0).min(5);
+            }
+        }
+        filter
+    }
+}
+
+/// Case-insensitive subsequence match. Higher is better; consecutive hits
+/// and hits at the start of a word score extra.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut prev: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = (pos..text.len()).find(|&i| text[i] == q)?;
+        score += 1;
+        if prev.is_some_and(|p| p + 1 == found) {
+            score += 3;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 2;
+        }
+        prev = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// Decode `%XX` escapes and `+` as space; malformed escapes are kept as-is.
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => {
+                        out.push(b);
+                        i += 2;
+                    }
+                    Err(_) => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,237 @@
+//! The ecosystem difficulty graph, exported for other tools: Graphviz DOT,
+//! Mermaid flowcharts and GEXF for Gephi.
+
+use crate::api::{mode_variants, path_key};
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::{compute_migration, difficulty_band, DIFFICULTY_LABELS};
+use crate::render::escape;
+use crate::theme;
+
+pub struct GraphOptions {
+    /// Keep edges up to this band of `DIFFICULTY_LABELS`, e.g. 1 for "Easy or better".
+    pub max_band: usize,
+    /// One node per selectable deploy mode instead of per ecosystem.
+    pub per_mode: bool,
+    /// Keep only pairs that are within the threshold both ways, as undirected
+    /// edges scored by the harder direction.
+    pub mutual: bool,
+}
+
+pub struct GraphNode {
+    /// `<ecosystem>` or `<ecosystem>.<mode>`, as in the static API.
+    pub id: String,
+    pub label: String,
+    pub ecosystem: String,
+    pub mode: Option<String>,
+}
+
+pub struct GraphEdge {
+    pub source: usize,
+    pub target: usize,
+    pub overall: f64,
+    pub label: &'static str,
+}
+
+pub struct DifficultyGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    /// Whether edges are one-way migrations or mutual pairs.
+    pub directed: bool,
+}
+
+/// Look up a threshold by difficulty label, case-insensitively.
+pub fn difficulty_band_named(name: &str) -> Option<usize> {
+    DIFFICULTY_LABELS
+        .iter()
+        .position(|l| l.eq_ignore_ascii_case(name))
+}
+
+pub fn difficulty_graph(ecosystems: &[Ecosystem], options: &GraphOptions) -> DifficultyGraph {
+    let mut nodes = Vec::new();
+    let mut variants = Vec::new();
+    for eco in ecosystems {
+        let modes = if options.per_mode {
+            mode_variants(eco)
+        } else {
+            vec![None]
+        };
+        for mode in modes {
+            let label = match &mode {
+                Some(m) => format!("{} ({})", eco.name, m),
+                None => eco.name.clone(),
+            };
+            nodes.push(GraphNode {
+                id: path_key(eco, mode.as_deref()),
+                label,
+                ecosystem: eco.id.clone(),
+                mode: mode.clone(),
+            });
+            variants.push((eco, mode));
+        }
+    }
+
+    let overall = |i: usize, j: usize| {
+        let (src, sm) = &variants[i];
+        let (dst, dm) = &variants[j];
+        compute_migration(src, dst, sm.as_deref(), dm.as_deref()).overall
+    };
+
+    let mut edges = Vec::new();
+    for i in 0..nodes.len() {
+        for j in 0..nodes.len() {
+            if nodes[i].ecosystem == nodes[j].ecosystem || (options.mutual && j <= i) {
+                continue;
+            }
+            let score = if options.mutual {
+                overall(i, j).max(overall(j, i))
+            } else {
+                overall(i, j)
+            };
+            let band = difficulty_band(score);
+            if band <= options.max_band {
+                edges.push(GraphEdge {
+                    source: i,
+                    target: j,
+                    overall: score,
+                    label: DIFFICULTY_LABELS[band],
+                });
+            }
+        }
+    }
+
+    DifficultyGraph {
+        nodes,
+        edges,
+        directed: !options.mutual,
+    }
+}
+
+/// Edge colors by band, matching the ring view.
+const BAND_COLORS: [&str; 5] = [
+    theme::LIGHT_TEAL,
+    theme::TEAL,
+    theme::CYAN,
+    theme::DARK_NAVY,
+    theme::RED,
+];
+
+fn dot_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub fn graph_dot(graph: &DifficultyGraph) -> String {
+    let (kind, arrow) = if graph.directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let mut out = format!("{} difficulty {{\n  node [shape=box];\n", kind);
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "  {} [label={}];\n",
+            dot_string(&node.id),
+            dot_string(&node.label)
+        ));
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "  {} {} {} [label=\"{} {:.2}\", weight={:.3}, color=\"{}\"];\n",
+            dot_string(&graph.nodes[edge.source].id),
+            arrow,
+            dot_string(&graph.nodes[edge.target].id),
+            edge.label,
+            edge.overall,
+            1.0 - edge.overall,
+            BAND_COLORS[difficulty_band(edge.overall)]
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid node ids only allow a safe subset of characters.
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+pub fn graph_mermaid(graph: &DifficultyGraph) -> String {
+    let arrow = if graph.directed { "-->" } else { "---" };
+    let mut out = String::from("flowchart LR\n");
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "  {}[\"{}\"]\n",
+            mermaid_id(&node.id),
+            node.label.replace('"', "#quot;")
+        ));
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "  {} {}|\"{} {:.2}\"| {}\n",
+            mermaid_id(&graph.nodes[edge.source].id),
+            arrow,
+            edge.label,
+            edge.overall,
+            mermaid_id(&graph.nodes[edge.target].id)
+        ));
+    }
+    out
+}
+
+/// GEXF 1.3 for Gephi. Edge `weight` is ease (1 − overall) so force layouts
+/// pull easy pairs together; `overall` and `difficulty` are kept as attributes.
+pub fn graph_gexf(graph: &DifficultyGraph) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n",
+    );
+    out.push_str(&format!(
+        "  <graph mode=\"static\" defaultedgetype=\"{}\">\n",
+        if graph.directed {
+            "directed"
+        } else {
+            "undirected"
+        }
+    ));
+    out.push_str(
+        "    <attributes class=\"node\">\n\
+         \x20     <attribute id=\"ecosystem\" title=\"ecosystem\" type=\"string\"/>\n\
+         \x20     <attribute id=\"mode\" title=\"mode\" type=\"string\"/>\n\
+         \x20   </attributes>\n\
+         \x20   <attributes class=\"edge\">\n\
+         \x20     <attribute id=\"overall\" title=\"overall\" type=\"double\"/>\n\
+         \x20     <attribute id=\"difficulty\" title=\"difficulty\" type=\"string\"/>\n\
+         \x20   </attributes>\n\
+         \x20   <nodes>\n",
+    );
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "      <node id=\"{}\" label=\"{}\">\n        <attvalues>\n\
+             \x20         <attvalue for=\"ecosystem\" value=\"{}\"/>\n\
+             \x20         <attvalue for=\"mode\" value=\"{}\"/>\n\
+             \x20       </attvalues>\n      </node>\n",
+            escape(&node.id),
+            escape(&node.label),
+            escape(&node.ecosystem),
+            escape(node.mode.as_deref().unwrap_or(""))
+        ));
+    }
+    out.push_str("    </nodes>\n    <edges>\n");
+    for (i, edge) in graph.edges.iter().enumerate() {
+        out.push_str(&format!(
+            "      <edge id=\"{}\" source=\"{}\" target=\"{}\" weight=\"{:.4}\">\n        <attvalues>\n\
+             \x20         <attvalue for=\"overall\" value=\"{:.4}\"/>\n\
+             \x20         <attvalue for=\"difficulty\" value=\"{}\"/>\n\
+             \x20       </attvalues>\n      </edge>\n",
+            i,
+            escape(&graph.nodes[edge.source].id),
+            escape(&graph.nodes[edge.target].id),
+            1.0 - edge.overall,
+            edge.overall,
+            edge.label
+        ));
+    }
+    out.push_str("    </edges>\n  </graph>\n</gexf>\n");
+    out
+}
@@ -0,0 +1,221 @@
+//! Card layouts: the reflowing grid, and the similarity map (classical
+//! multidimensional scaling of symmetric migration difficulty, so easy pairs
+//! sit close together).
+
+use crate::data::ecosystem::Ecosystem;
+use crate::data::scoring::{compute_migration_weighted, Weights};
+
+const POWER_ITERATIONS: usize = 300;
+
+/// Grid card size in pixels.
+pub const CARD_SIZE: [f64; 2] = [180.0, 130.0];
+const GRID_GAP: [f64; 2] = [30.0, 70.0];
+const GRID_MARGIN: [f64; 2] = [80.0, 60.0];
+
+/// Columns that fit in a viewport `width` wide; at least one.
+pub fn grid_columns(width: f64) -> usize {
+    let usable = width - 2.0 * GRID_MARGIN[0] + GRID_GAP[0];
+    ((usable / (CARD_SIZE[0] + GRID_GAP[0])).floor() as usize).max(1)
+}
+
+/// Top-left card positions for the grid view. Groups follow their first
+/// appearance; a group starts a new row unless it fits in what's left of the
+/// current one, and wraps when it is wider than the grid. Ecosystems with a
+/// pinned `position` keep it and don't take a slot.
+pub fn grid_layout(ecosystems: &[Ecosystem], width: f64) -> Vec<[f64; 2]> {
+    let columns = grid_columns(width);
+    let mut groups: Vec<&str> = Vec::new();
+    for eco in ecosystems {
+        if eco.position.is_none() && !groups.contains(&eco.group.as_str()) {
+            groups.push(&eco.group);
+        }
+    }
+
+    let mut slots = vec![[0, 0]; ecosystems.len()];
+    let (mut row, mut col) = (0, 0);
+    for group in groups {
+        let members: Vec<usize> = (0..ecosystems.len())
+            .filter(|&i| ecosystems[i].position.is_none() && ecosystems[i].group == group)
+            .collect();
+        if col > 0 && col + members.len() > columns {
+            row += 1;
+            col = 0;
+        }
+        for i in members {
+            if col == columns {
+                row += 1;
+                col = 0;
+            }
+            slots[i] = [col, row];
+            col += 1;
+        }
+    }
+
+    ecosystems
+        .iter()
+        .zip(slots)
+        .map(|(eco, [col, row])| {
+            eco.position.unwrap_or([
+                GRID_MARGIN[0] + col as f64 * (CARD_SIZE[0] + GRID_GAP[0]),
+                GRID_MARGIN[1] + row as f64 * (CARD_SIZE[1] + GRID_GAP[1]),
+            ])
+        })
+        .collect()
+}
+
+/// Height needed to show every card, bottom margin included.
+pub fn grid_height(positions: &[[f64; 2]]) -> f64 {
+    positions.iter().map(|p| p[1]).fold(0.0, f64::max) + CARD_SIZE[1] + GRID_MARGIN[1]
+}
+
+/// Symmetric difficulty: the mean of both migration directions.
+pub fn distance_matrix(ecosystems: &[Ecosystem], weights: &Weights) -> Vec<Vec<f64>> {
+    let n = ecosystems.len();
+    let mut d = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let there =
+                compute_migration_weighted(&ecosystems[i], &ecosystems[j], None, None, weights);
+            let back =
+                compute_migration_weighted(&ecosystems[j], &ecosystems[i], None, None, weights);
+            let mean = (there.overall + back.overall) / 2.0;
+            d[i][j] = mean;
+            d[j][i] = mean;
+        }
+    }
+    d
+}
+
+fn mat_vec(m: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    m.iter()
+        .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Dominant eigenpair of a symmetric matrix by power iteration. The start
+/// vector is fixed so the layout is deterministic.
+fn dominant_eigen(m: &[Vec<f64>]) -> (f64, Vec<f64>) {
+    let n = m.len();
+    let mut v: Vec<f64> = (0..n).map(|i| ((i + 1) as f64).sin()).collect();
+    let mut lambda = 0.0;
+    for _ in 0..POWER_ITERATIONS {
+        let w = mat_vec(m, &v);
+        let len = norm(&w);
+        if len == 0.0 {
+            return (0.0, vec![0.0; n]);
+        }
+        v = w.into_iter().map(|x| x / len).collect();
+        lambda = len;
+    }
+    // Power iteration finds the largest magnitude; recover the sign.
+    let rayleigh: f64 = mat_vec(m, &v).iter().zip(&v).map(|(a, b)| a * b).sum();
+    (lambda.copysign(rayleigh), v)
+}
+
+/// Classical MDS into two dimensions.
+pub fn classical_mds(distances: &[Vec<f64>]) -> Vec<[f64; 2]> {
+    let n = distances.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Double-centered squared distances: B = -½ J D² J.
+    let sq: Vec<Vec<f64>> = distances
+        .iter()
+        .map(|row| row.iter().map(|d| d * d).collect())
+        .collect();
+    let row_mean: Vec<f64> = sq
+        .iter()
+        .map(|r| r.iter().sum::<f64>() / n as f64)
+        .collect();
+    let grand_mean = row_mean.iter().sum::<f64>() / n as f64;
+    let mut b: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| -0.5 * (sq[i][j] - row_mean[i] - row_mean[j] + grand_mean))
+                .collect()
+        })
+        .collect();
+
+    let mut axes = Vec::new();
+    for _ in 0..2 {
+        let (lambda, mut v) = dominant_eigen(&b);
+        for i in 0..n {
+            for j in 0..n {
+                b[i][j] -= lambda * v[i] * v[j];
+            }
+        }
+        // Eigenvectors have no inherent sign; pin the first ecosystem to the
+        // low side so the map doesn't mirror when weights change.
+        if v[0] > 0.0 {
+            v.iter_mut().for_each(|x| *x = -*x);
+        }
+        let scale = lambda.max(0.0).sqrt();
+        axes.push(v.into_iter().map(|x| x * scale).collect::<Vec<_>>());
+    }
+
+    (0..n).map(|i| [axes[0][i], axes[1][i]]).collect()
+}
+
+/// Fit points into the unit square, keeping their aspect ratio and
+/// centering the shorter axis.
+pub fn normalize(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    let bounds = |axis: usize| {
+        points.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+            (lo.min(p[axis]), hi.max(p[axis]))
+        })
+    };
+    let (x0, x1) = bounds(0);
+    let (y0, y1) = bounds(1);
+    let span = (x1 - x0).max(y1 - y0);
+    if span <= 0.0 {
+        return vec![[0.5, 0.5]; points.len()];
+    }
+    let (pad_x, pad_y) = ((span - (x1 - x0)) / 2.0, (span - (y1 - y0)) / 2.0);
+    points
+        .iter()
+        .map(|p| [(p[0] - x0 + pad_x) / span, (p[1] - y0 + pad_y) / span])
+        .collect()
+}
+
+/// Ecosystem positions in the unit square for the given weights.
+pub fn similarity_layout(ecosystems: &[Ecosystem], weights: &Weights) -> Vec<[f64; 2]> {
+    normalize(&classical_mds(&distance_matrix(ecosystems, weights)))
+}
+
+/// Nudge boxes of `width` × `height` centered on `points` apart until they
+/// no longer overlap (or `iterations` runs out), moving along the axis with
+/// the smaller overlap.
+pub fn remove_overlaps(points: &mut [[f64; 2]], width: f64, height: f64, iterations: usize) {
+    for _ in 0..iterations {
+        let mut moved = false;
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let dx = points[j][0] - points[i][0];
+                let dy = points[j][1] - points[i][1];
+                let overlap_x = width - dx.abs();
+                let overlap_y = height - dy.abs();
+                if overlap_x <= 0.0 || overlap_y <= 0.0 {
+                    continue;
+                }
+                moved = true;
+                if overlap_x / width < overlap_y / height {
+                    let push = overlap_x / 2.0 * if dx < 0.0 { -1.0 } else { 1.0 };
+                    points[i][0] -= push;
+                    points[j][0] += push;
+                } else {
+                    let push = overlap_y / 2.0 * if dy < 0.0 { -1.0 } else { 1.0 };
+                    points[i][1] -= push;
+                    points[j][1] += push;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+}
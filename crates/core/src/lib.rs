@@ -0,0 +1,14 @@
+//! Dataset and scoring engine shared by the web app, the CLI and the
+//! language bindings. The dataset is compiled in from `data/*.toml` by
+//! `build.rs`.
+
+pub mod api;
+pub mod data;
+pub mod diff;
+pub mod export;
+pub mod filter;
+pub mod graph;
+pub mod layout;
+pub mod render;
+pub mod text;
+pub mod theme;
@@ -0,0 +1,266 @@
+//! Self-contained SVG and HTML renders of a migration report, laid out like
+//! the migration panel. Used for printing and by the CLI's `render` command.
+
+use crate::export::ReportExport;
+use crate::text::{finding_text, mitigation_category_label};
+use crate::theme;
+
+const WIDTH: f64 = 360.0;
+const PAD: f64 = 24.0;
+const INNER: f64 = WIDTH - 2.0 * PAD;
+/// Approximate advance of a 12px monospace glyph, for wrapping.
+const CHAR_W: f64 = 7.2;
+
+/// Escape text for XML attributes and content.
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Greedy word wrap to a column count.
+fn wrap(text: &str, cols: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > cols {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Accumulates SVG elements while tracking the vertical cursor.
+struct Canvas {
+    body: String,
+    y: f64,
+}
+
+impl Canvas {
+    fn text(&mut self, x: f64, size: f64, weight: u16, color: &str, s: &str) {
+        self.body.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{}\" font-weight=\"{}\" fill=\"{}\">{}</text>\n",
+            x,
+            self.y,
+            size,
+            weight,
+            color,
+            escape(s)
+        ));
+    }
+
+    fn bar(&mut self, height: f64, fraction: f64, fill: &str) {
+        let radius = height / 2.0;
+        self.body.push_str(&format!(
+            "<rect x=\"{PAD}\" y=\"{:.1}\" width=\"{INNER}\" height=\"{height}\" rx=\"{radius}\" fill=\"{}\"/>\n",
+            self.y,
+            theme::LIGHT_GRAY
+        ));
+        self.body.push_str(&format!(
+            "<rect x=\"{PAD}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{height}\" rx=\"{radius}\" fill=\"{fill}\"/>\n",
+            self.y,
+            INNER * fraction.clamp(0.0, 1.0)
+        ));
+    }
+
+    fn rule(&mut self) {
+        self.body.push_str(&format!(
+            "<line x1=\"{PAD}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\"/>\n",
+            self.y,
+            WIDTH - PAD,
+            self.y,
+            theme::LIGHT_GRAY
+        ));
+    }
+
+    /// A bordered list item, like `.challenge-item` / `.positive-item`.
+    fn item(&mut self, text: &str, border: &str) {
+        let cols = ((INNER - 12.0) / CHAR_W) as usize;
+        let lines = wrap(text, cols);
+        let top = self.y - 12.0;
+        for line in &lines {
+            self.text(PAD + 12.0, 12.0, 400, theme::SLATE, line);
+            self.y += 18.0;
+        }
+        self.body.push_str(&format!(
+            "<rect x=\"{PAD}\" y=\"{:.1}\" width=\"2\" height=\"{:.1}\" fill=\"{}\"/>\n",
+            top,
+            lines.len() as f64 * 18.0 - 2.0,
+            border
+        ));
+    }
+
+    fn sub_item(&mut self, text: &str) {
+        let cols = ((INNER - 24.0) / (CHAR_W * 11.0 / 12.0)) as usize;
+        for line in wrap(text, cols) {
+            self.text(PAD + 24.0, 11.0, 400, theme::BLUE_GRAY, &line);
+            self.y += 16.0;
+        }
+    }
+}
+
+/// Render a report as a standalone SVG document.
+pub fn report_svg(export: &ReportExport) -> String {
+    let inputs = &export.inputs;
+    let r = &export.report;
+    let mut c = Canvas {
+        body: String::new(),
+        y: PAD + 12.0,
+    };
+
+    c.text(PAD, 13.0, 700, theme::TEAL, "MIGRATION ANALYSIS");
+    c.y += 32.0;
+    let route = format!("{} → {}", inputs.source.name, inputs.dest.name);
+    c.text(PAD, 18.0, 700, theme::DARK_NAVY, &route);
+    c.y += 20.0;
+    let modes = [
+        inputs
+            .src_mode
+            .as_deref()
+            .map(|m| format!("{} as {}", inputs.source.short, m)),
+        inputs
+            .dst_mode
+            .as_deref()
+            .map(|m| format!("{} as {}", inputs.dest.short, m)),
+    ];
+    let modes: Vec<String> = modes.into_iter().flatten().collect();
+    if !modes.is_empty() {
+        c.text(PAD, 11.0, 400, theme::SLATE, &modes.join(" · "));
+        c.y += 20.0;
+    }
+
+    // ── Overall difficulty ──
+    c.y += 12.0;
+    c.text(PAD, 12.0, 400, theme::SLATE, "OVERALL DIFFICULTY");
+    c.y += 8.0;
+    c.bar(8.0, r.overall, theme::CYAN);
+    c.y += 40.0;
+    c.text(
+        PAD,
+        28.0,
+        700,
+        theme::DARK_NAVY,
+        &format!("{:.1}/5", r.overall * 5.0),
+    );
+    c.y += 20.0;
+    c.text(PAD, 13.0, 400, theme::SLATE, &r.difficulty_label);
+    c.y += 36.0;
+
+    // ── Dimensions ──
+    for d in &r.dimensions {
+        let name = if d.overridden {
+            format!("{} *", d.name.to_uppercase())
+        } else {
+            d.name.to_uppercase()
+        };
+        c.text(PAD, 12.0, 400, theme::SLATE, &name);
+        c.body.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"12\" font-weight=\"600\" fill=\"{}\" text-anchor=\"end\">{}</text>\n",
+            WIDTH - PAD,
+            c.y,
+            theme::DARK_SLATE,
+            escape(&d.label)
+        ));
+        c.y += 6.0;
+        let fill = if d.overridden {
+            theme::TEAL
+        } else {
+            theme::LIGHT_TEAL
+        };
+        c.bar(4.0, d.score, fill);
+        c.y += 26.0;
+    }
+
+    if let Some(ov) = r.pair_override {
+        c.sub_item(&format!("* Curated override: {}", ov.justification));
+        c.y += 8.0;
+    }
+
+    // ── Findings ──
+    c.rule();
+    c.y += 28.0;
+    c.text(PAD, 12.0, 700, theme::RED, "KEY CHALLENGES");
+    c.y += 24.0;
+    for ch in &r.challenges {
+        c.item(&finding_text(ch), theme::RED);
+        for m in &ch.mitigations {
+            c.sub_item(&format!(
+                "{}: {}",
+                mitigation_category_label(m.category),
+                m.text
+            ));
+        }
+        c.y += 8.0;
+    }
+
+    if !r.positives.is_empty() {
+        c.y += 12.0;
+        c.text(PAD, 12.0, 700, theme::TEAL, "ADVANTAGES");
+        c.y += 24.0;
+        for p in &r.positives {
+            c.item(&finding_text(p), theme::TEAL);
+            c.y += 8.0;
+        }
+    }
+
+    // ── Effort ──
+    let e = &export.effort;
+    c.rule();
+    c.y += 28.0;
+    c.text(PAD, 12.0, 700, theme::TEAL, "EFFORT ESTIMATE");
+    c.y += 22.0;
+    for p in &e.phases {
+        c.text(PAD, 12.0, 400, theme::SLATE, p.phase.name());
+        c.body.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"12\" font-weight=\"600\" fill=\"{}\" text-anchor=\"end\">{:.0}–{:.0} pw</text>\n",
+            WIDTH - PAD,
+            c.y,
+            theme::DARK_SLATE,
+            p.low.ceil(),
+            p.high.ceil()
+        ));
+        c.y += 18.0;
+    }
+    c.y += 8.0;
+    c.text(
+        PAD,
+        16.0,
+        700,
+        theme::DARK_NAVY,
+        &format!("{:.0}–{:.0} person-weeks", e.low.ceil(), e.high.ceil()),
+    );
+    c.y += 28.0;
+    c.text(
+        PAD,
+        10.0,
+        400,
+        theme::BLUE_GRAY,
+        &export.dataset_label(),
+    );
+    c.y += PAD;
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{h:.0}\" viewBox=\"0 0 {WIDTH} {h:.0}\" font-family=\"{font}\">\n<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n{body}</svg>\n",
+        h = c.y,
+        font = escape(theme::FONT_MONO),
+        body = c.body
+    )
+}
+
+/// Wrap the SVG in a minimal HTML page that prints on its own.
+pub fn report_html(export: &ReportExport) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\" />\n<title>Migration: {} → {}</title>\n<style>\nbody {{ margin: 0; display: flex; justify-content: center; background: #f4f3f2; }}\nsvg {{ background: #ffffff; }}\n@media print {{ body {{ background: none; }} @page {{ margin: 12mm; }} }}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(&export.inputs.source.name),
+        escape(&export.inputs.dest.name),
+        report_svg(export)
+    )
+}
@@ -0,0 +1,195 @@
+//! English rendering of structured report data. Keeping wording here, out of
+//! the scoring engine, leaves room for other locales and machine-readable
+//! exports of the same findings.
+
+use crate::data::findings::{Finding, FindingKind, Severity};
+use crate::data::mitigations::MitigationCategory;
+use crate::data::plan::{PlanSection, PlanStep, StepKind};
+
+pub fn finding_text(f: &Finding) -> String {
+    let p = &f.params;
+    let from = p.from.join(", ");
+    let to = p.to.join(", ");
+    let rating = p.rating.unwrap_or(0);
+
+    match f.kind {
+        FindingKind::SameLanguage => {
+            format!("Same language ({}) — existing code may port directly", from)
+        }
+        FindingKind::RelatedLanguageFamily => {
+            "Related language family — developer skills transfer well".to_string()
+        }
+        FindingKind::SameVm => format!("Same VM ({}) — runtime behavior is identical", from),
+        FindingKind::BothEvmNative => {
+            "Both EVM-native — tooling, libraries, and patterns transfer directly".to_string()
+        }
+        FindingKind::SameTxModel => {
+            "Same transaction model — no paradigm shift required".to_string()
+        }
+        FindingKind::SameDeployModel => {
+            "Same deployment model — no infrastructure changes needed".to_string()
+        }
+        FindingKind::ExcellentTooling => format!("Excellent destination tooling ({}/5)", rating),
+        FindingKind::StrongDocs => format!("Strong destination documentation ({}/5)", rating),
+        FindingKind::WellFunded => format!(
+            "Well-funded destination ecosystem ({}/5) — grants and support available",
+            rating
+        ),
+        FindingKind::LanguageChange => {
+            format!("Completely different languages: {} → {}", from, to)
+        }
+        FindingKind::RelatedLanguageChange => {
+            format!("Related but distinct languages: {} → {}", from, to)
+        }
+        FindingKind::VmChange => format!("Different VM architecture: {} → {}", from, to),
+        FindingKind::TxModelChange => format!("Different transaction model: {} → {}", from, to),
+        FindingKind::LeavingEvm => {
+            "Leaving the EVM ecosystem — existing tooling won't transfer".to_string()
+        }
+        FindingKind::EnteringEvm => {
+            "Entering the EVM ecosystem — different paradigm from source".to_string()
+        }
+        FindingKind::DeployModelChange => format!(
+            "Different deployment model: {} → {}",
+            p.from.join("/"),
+            p.to.join("/")
+        ),
+        FindingKind::ImmatureTooling => {
+            format!("Destination tooling is immature ({}/5)", rating)
+        }
+        FindingKind::LimitedDocs => {
+            format!("Destination documentation is limited ({}/5)", rating)
+        }
+        FindingKind::L2Gap => "Significant L2/rollup ecosystem gap".to_string(),
+        FindingKind::LimitedFunding => format!(
+            "Limited ecosystem funding ({}/5) — fewer grants and support programs",
+            rating
+        ),
+        FindingKind::Curated => p.note.clone().unwrap_or_default(),
+    }
+}
+
+pub fn severity_label(s: Severity) -> &'static str {
+    match s {
+        Severity::Info => "Info",
+        Severity::Minor => "Minor",
+        Severity::Major => "Major",
+        Severity::Critical => "Critical",
+    }
+}
+
+/// CSS modifier class for a finding's severity.
+pub fn severity_class(s: Severity) -> &'static str {
+    match s {
+        Severity::Info => "severity-info",
+        Severity::Minor => "severity-minor",
+        Severity::Major => "severity-major",
+        Severity::Critical => "severity-critical",
+    }
+}
+
+pub fn mitigation_category_label(c: MitigationCategory) -> &'static str {
+    match c {
+        MitigationCategory::Compatibility => "Compatibility layer",
+        MitigationCategory::Transpiler => "Transpiler",
+        MitigationCategory::Framework => "Framework",
+        MitigationCategory::Learning => "Learning",
+    }
+}
+
+pub fn plan_section_name(s: PlanSection) -> &'static str {
+    match s {
+        PlanSection::Skills => "Skill ramp-up",
+        PlanSection::Tooling => "Tooling",
+        PlanSection::Rewrite => "Rewrite",
+        PlanSection::Deployment => "Deployment",
+        PlanSection::Audit => "Audit",
+        PlanSection::Funding => "Funding",
+    }
+}
+
+pub fn plan_step_title(step: &PlanStep) -> String {
+    let subject = &step.subject;
+    match step.kind {
+        StepKind::LearnLanguage => format!("Learn {}", subject),
+        StepKind::LearnVm => format!("Learn the {} execution model", subject),
+        StepKind::ReplaceTool => match &step.counterpart {
+            Some(to) => format!("Replace {} with {}", subject, to),
+            None => format!("Find a replacement for {}", subject),
+        },
+        StepKind::AdoptTool => format!("Adopt {}", subject),
+        StepKind::RewriteForTxModel => format!(
+            "Redesign state for the {} model",
+            step.counterpart.as_deref().unwrap_or_default()
+        ),
+        StepKind::PortContracts => format!("Port contracts to {}", subject),
+        StepKind::RetestContracts => format!("Re-run the test suite against {}", subject),
+        StepKind::ChooseDeployment => format!("Choose a deployment target: {}", subject),
+        StepKind::DeployAs => format!("Deploy as {}", subject),
+        StepKind::FullAudit => format!("Full security audit for {}", subject),
+        StepKind::DeltaAudit => format!("Delta audit of {}-specific changes", subject),
+        StepKind::ApplyForGrants => format!("Apply for {} ecosystem grants", subject),
+        StepKind::SeekFunding => "Line up funding outside the destination ecosystem".to_string(),
+    }
+}
+
+pub fn plan_step_detail(step: &PlanStep) -> String {
+    let category = step.category.unwrap_or_default();
+    match step.kind {
+        StepKind::LearnLanguage => format!(
+            "Get the team productive in {} before porting production code.",
+            step.subject
+        ),
+        StepKind::LearnVm => format!(
+            "Study {}'s execution limits, fee model and storage semantics.",
+            step.subject
+        ),
+        StepKind::ReplaceTool => match &step.counterpart {
+            Some(to) => format!(
+                "{}: move workflows from {} to {}.",
+                category, step.subject, to
+            ),
+            None => format!(
+                "{}: the destination has no listed counterpart for {}.",
+                category, step.subject
+            ),
+        },
+        StepKind::AdoptTool => format!(
+            "{}: the source has no equivalent; set up {}.",
+            category, step.subject
+        ),
+        StepKind::RewriteForTxModel => format!(
+            "Moving from {} to {} changes how state is stored and accessed.",
+            step.subject,
+            step.counterpart.as_deref().unwrap_or_default()
+        ),
+        StepKind::PortContracts => {
+            "Rewrite contract logic and tests in the destination language.".to_string()
+        }
+        StepKind::RetestContracts => {
+            "Same language — recompile and run the full suite on the destination.".to_string()
+        }
+        StepKind::ChooseDeployment => {
+            "Pick the deployment option that matches your security and sovereignty needs."
+                .to_string()
+        }
+        StepKind::DeployAs => format!(
+            "Set up {} deployment pipelines and infrastructure.",
+            step.subject
+        ),
+        StepKind::FullAudit => {
+            "New language or VM — budget for a full audit, not a diff review.".to_string()
+        }
+        StepKind::DeltaAudit => {
+            "Code carries over — audit the destination-specific changes.".to_string()
+        }
+        StepKind::ApplyForGrants => format!(
+            "Destination funding is rated {}/5; migration grants are likely available.",
+            step.rating.unwrap_or(0)
+        ),
+        StepKind::SeekFunding => format!(
+            "Destination funding is rated {}/5; plan for external support.",
+            step.rating.unwrap_or(0)
+        ),
+    }
+}
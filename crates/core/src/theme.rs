@@ -0,0 +1,10 @@
+pub const CYAN: &str = "#1bb5ce";
+pub const TEAL: &str = "#2897a3";
+pub const LIGHT_TEAL: &str = "#92ccd6";
+pub const LIGHT_GRAY: &str = "#e5e5e5";
+pub const BLUE_GRAY: &str = "#9bafbf";
+pub const SLATE: &str = "#667489";
+pub const DARK_SLATE: &str = "#273544";
+pub const DARK_NAVY: &str = "#032144";
+pub const RED: &str = "#85241e";
+pub const FONT_MONO: &str = "'JetBrains Mono', 'Fira Code', 'Cascadia Code', monospace";
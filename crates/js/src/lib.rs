@@ -0,0 +1,204 @@
+//! JavaScript/TypeScript bindings for the scoring engine. Build an npm
+//! package with `wasm-pack build crates/js`.
+
+use std::sync::OnceLock;
+
+use bc_techmap_core::api::{deploy_mode, RankResponse};
+use bc_techmap_core::data::ecosystem::{load_ecosystems, Ecosystem};
+use bc_techmap_core::data::scoring::{
+    compute_migration_weighted, Dimension, Weights, WEIGHT_PROFILES,
+};
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPES: &str = r#"
+export interface DeployMode {
+  languages: string[];
+}
+
+export interface Provenance {
+  source: string;
+  /** YYYY-MM-DD */
+  last_verified: string;
+}
+
+export interface Ecosystem {
+  id: string;
+  name: string;
+  short: string;
+  languages: string[];
+  vm: string;
+  transaction_model: string;
+  evm_compatibility: string;
+  deployment_options: string[];
+  chain_layer: string;
+  l2_maturity: number;
+  consensus: string;
+  tooling_maturity: number;
+  tooling: string[];
+  doc_quality: number;
+  ecosystem_funding: number;
+  group: string;
+  position: [number, number] | null;
+  deploy_modes: Record<string, DeployMode> | null;
+  /** Keyed by field name, or `default` for fields without their own entry. */
+  provenance?: Record<string, Provenance>;
+}
+
+export type Dimension =
+  | "language" | "vm" | "tx_model" | "evm_compat" | "deploy"
+  | "tooling" | "docs" | "l2" | "funding";
+
+/** Relative dimension weights; omitted fields keep their default. */
+export type Weights = Partial<Record<Dimension, number>>;
+
+export interface DimensionScore {
+  dimension: Dimension;
+  name: string;
+  /** 0 (trivial) to 1 (very hard). */
+  score: number;
+  label: string;
+  overridden: boolean;
+}
+
+export type Severity = "info" | "minor" | "major" | "critical";
+
+export interface Mitigation {
+  category: "compatibility" | "transpiler" | "framework" | "learning";
+  text: string;
+  url: string | null;
+}
+
+export interface Finding {
+  kind: string;
+  severity: Severity;
+  dimension: Dimension | null;
+  params: {
+    from: string[];
+    to: string[];
+    rating: number | null;
+    note: string | null;
+  };
+  mitigations: Mitigation[];
+}
+
+export interface PairOverride {
+  source: string;
+  dest: string;
+  src_mode: string | null;
+  dst_mode: string | null;
+  adjustments: [Dimension, number][];
+  positives: string[];
+  challenges: string[];
+  justification: string;
+  source_url: string;
+}
+
+export interface MigrationReport {
+  overall: number;
+  difficulty_label: string;
+  dimensions: DimensionScore[];
+  challenges: Finding[];
+  positives: Finding[];
+  pair_override: PairOverride | null;
+}
+
+export interface RankEntry {
+  dest: string;
+  name: string;
+  overall: number;
+  difficulty_label: string;
+}
+"#;
+
+fn ecosystems() -> &'static [Ecosystem] {
+    static ECOSYSTEMS: OnceLock<Vec<Ecosystem>> = OnceLock::new();
+    ECOSYSTEMS.get_or_init(load_ecosystems)
+}
+
+fn find(id: &str) -> Result<&'static Ecosystem, JsError> {
+    ecosystems()
+        .iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| JsError::new(&format!("unknown ecosystem {:?}", id)))
+}
+
+/// `undefined` and `null` mean the default weights.
+fn parse_weights(value: JsValue) -> Result<Weights, JsError> {
+    if value.is_undefined() || value.is_null() {
+        return Ok(Weights::default());
+    }
+    let weights: Weights = serde_wasm_bindgen::from_value(value)?;
+    if Dimension::ALL
+        .iter()
+        .any(|&d| weights.get(d).is_nan() || weights.get(d) < 0.0)
+    {
+        return Err(JsError::new("weights must be non-negative numbers"));
+    }
+    Ok(weights)
+}
+
+/// Plain objects rather than `Map`s, so results survive `JSON.stringify`.
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    Ok(value.serialize(&Serializer::json_compatible())?)
+}
+
+#[wasm_bindgen(js_name = listEcosystems, unchecked_return_type = "Ecosystem[]")]
+pub fn list_ecosystems() -> Result<JsValue, JsError> {
+    to_js(&ecosystems())
+}
+
+/// Named weight presets accepted by `weightProfile`.
+#[wasm_bindgen(js_name = weightProfiles)]
+pub fn weight_profiles() -> Vec<String> {
+    WEIGHT_PROFILES.iter().map(|p| p.to_string()).collect()
+}
+
+#[wasm_bindgen(js_name = weightProfile, unchecked_return_type = "Required<Weights>")]
+pub fn weight_profile(name: &str) -> Result<JsValue, JsError> {
+    let weights = Weights::profile(name)
+        .ok_or_else(|| JsError::new(&format!("unknown weight profile {:?}", name)))?;
+    to_js(&weights)
+}
+
+#[wasm_bindgen(js_name = computeMigration, unchecked_return_type = "MigrationReport")]
+pub fn compute_migration(
+    src: &str,
+    dst: &str,
+    #[wasm_bindgen(js_name = srcMode)] src_mode: Option<String>,
+    #[wasm_bindgen(js_name = dstMode)] dst_mode: Option<String>,
+    #[wasm_bindgen(unchecked_param_type = "Weights | undefined")] weights: JsValue,
+) -> Result<JsValue, JsError> {
+    let src = find(src)?;
+    let dst = find(dst)?;
+    let src_mode = deploy_mode(src, src_mode.as_deref()).map_err(|e| JsError::new(&e))?;
+    let dst_mode = deploy_mode(dst, dst_mode.as_deref()).map_err(|e| JsError::new(&e))?;
+    let report = compute_migration_weighted(
+        src,
+        dst,
+        src_mode.as_deref(),
+        dst_mode.as_deref(),
+        &parse_weights(weights)?,
+    );
+    to_js(&report)
+}
+
+/// Every other ecosystem ranked by migration difficulty, easiest first.
+#[wasm_bindgen(unchecked_return_type = "RankEntry[]")]
+pub fn rank(
+    src: &str,
+    #[wasm_bindgen(js_name = srcMode)] src_mode: Option<String>,
+    #[wasm_bindgen(unchecked_param_type = "Weights | undefined")] weights: JsValue,
+) -> Result<JsValue, JsError> {
+    let src = find(src)?;
+    let src_mode = deploy_mode(src, src_mode.as_deref()).map_err(|e| JsError::new(&e))?;
+    let response = RankResponse::new(
+        src,
+        src_mode.as_deref(),
+        ecosystems(),
+        parse_weights(weights)?,
+    );
+    to_js(&response.ranking)
+}
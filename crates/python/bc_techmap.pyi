@@ -0,0 +1,59 @@
+from typing import Literal, Optional, TypedDict
+
+Dimension = Literal[
+    "language", "vm", "tx_model", "evm_compat", "deploy", "tooling", "docs", "l2", "funding"
+]
+
+DATASET_VERSION: str
+
+class Ecosystem(TypedDict):
+    id: str
+    name: str
+    short: str
+    languages: list[str]
+    vm: str
+    transaction_model: str
+    evm_compatibility: str
+    deployment_options: list[str]
+    chain_layer: str
+    l2_maturity: int
+    consensus: str
+    tooling_maturity: int
+    tooling: list[str]
+    doc_quality: int
+    ecosystem_funding: int
+    group: str
+    position: Optional[list[float]]
+    deploy_modes: Optional[dict[str, dict[str, list[str]]]]
+
+class DimensionScore(TypedDict):
+    dimension: Dimension
+    name: str
+    score: float
+    label: str
+    overridden: bool
+
+class MigrationReport(TypedDict):
+    overall: float
+    difficulty_label: str
+    dimensions: list[DimensionScore]
+    challenges: list[dict]
+    positives: list[dict]
+    pair_override: Optional[dict]
+
+def ecosystems() -> list[Ecosystem]: ...
+def ecosystem(id: str) -> Ecosystem: ...
+def weight_profiles() -> list[str]: ...
+def weight_profile(name: str = "default") -> dict[Dimension, float]: ...
+def compute_migration(
+    src: str,
+    dst: str,
+    src_mode: Optional[str] = None,
+    dst_mode: Optional[str] = None,
+    weights: Optional[dict[str, float]] = None,
+    profile: Optional[str] = None,
+) -> MigrationReport: ...
+def matrix(
+    weights: Optional[dict[str, float]] = None,
+    profile: Optional[str] = None,
+) -> list[dict[str, object]]: ...
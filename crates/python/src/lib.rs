@@ -0,0 +1,180 @@
+//! Python bindings for the dataset and scoring engine. Build a wheel with
+//! `maturin build` (or `maturin develop` into a virtualenv) in
+//! `crates/python`.
+
+use std::collections::HashMap;
+
+use bc_techmap_core::api::deploy_mode;
+use bc_techmap_core::data::dataset::DATASET_VERSION;
+use bc_techmap_core::data::ecosystem::{load_ecosystems, Ecosystem};
+use bc_techmap_core::data::scoring::{
+    compute_migration_weighted, Dimension, Weights, WEIGHT_PROFILES,
+};
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Convert anything serializable into plain Python dicts, lists and scalars.
+fn to_py<'py, T: Serialize>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    let value = serde_json::to_value(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    json_to_py(py, &value)
+}
+
+fn json_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => b.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into_pyobject(py)?.into_any(),
+            None => n.as_f64().unwrap_or(f64::NAN).into_pyobject(py)?.into_any(),
+        },
+        Value::String(s) => s.into_pyobject(py)?.into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(k, json_to_py(py, v)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+fn find<'a>(ecosystems: &'a [Ecosystem], id: &str) -> PyResult<&'a Ecosystem> {
+    ecosystems
+        .iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| PyKeyError::new_err(format!("unknown ecosystem {:?}", id)))
+}
+
+/// Start from a named profile (or the defaults) and apply per-dimension
+/// overrides keyed like `Dimension::key`.
+fn resolve_weights(
+    profile: Option<&str>,
+    overrides: Option<HashMap<String, f64>>,
+) -> PyResult<Weights> {
+    let mut weights = match profile {
+        None => Weights::default(),
+        Some(name) => Weights::profile(name).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "unknown weight profile {:?} (options: {})",
+                name,
+                WEIGHT_PROFILES.join(", ")
+            ))
+        })?,
+    };
+    for (key, value) in overrides.unwrap_or_default() {
+        let dim = Dimension::ALL
+            .into_iter()
+            .find(|d| d.key() == key)
+            .ok_or_else(|| PyKeyError::new_err(format!("unknown dimension {:?}", key)))?;
+        if value.is_nan() || value < 0.0 {
+            return Err(PyValueError::new_err(
+                "weights must be non-negative numbers",
+            ));
+        }
+        weights.set(dim, value);
+    }
+    Ok(weights)
+}
+
+/// The dataset, one dict per ecosystem.
+#[pyfunction]
+fn ecosystems(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    to_py(py, &load_ecosystems())
+}
+
+#[pyfunction]
+fn ecosystem<'py>(py: Python<'py>, id: &str) -> PyResult<Bound<'py, PyAny>> {
+    to_py(py, find(&load_ecosystems(), id)?)
+}
+
+#[pyfunction]
+fn weight_profiles() -> Vec<&'static str> {
+    WEIGHT_PROFILES.to_vec()
+}
+
+/// The weights a profile resolves to, as a dict keyed by dimension.
+#[pyfunction]
+#[pyo3(signature = (name = "default"))]
+fn weight_profile<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+    to_py(py, &resolve_weights(Some(name), None)?)
+}
+
+/// Score a migration. `weights` maps dimension keys to relative weights and
+/// is applied on top of `profile`.
+#[pyfunction]
+#[pyo3(signature = (src, dst, src_mode = None, dst_mode = None, weights = None, profile = None))]
+fn compute_migration<'py>(
+    py: Python<'py>,
+    src: &str,
+    dst: &str,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+    weights: Option<HashMap<String, f64>>,
+    profile: Option<&str>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let ecosystems = load_ecosystems();
+    let src = find(&ecosystems, src)?;
+    let dst = find(&ecosystems, dst)?;
+    let src_mode = deploy_mode(src, src_mode).map_err(PyValueError::new_err)?;
+    let dst_mode = deploy_mode(dst, dst_mode).map_err(PyValueError::new_err)?;
+    let report = compute_migration_weighted(
+        src,
+        dst,
+        src_mode.as_deref(),
+        dst_mode.as_deref(),
+        &resolve_weights(profile, weights)?,
+    );
+    to_py(py, &report)
+}
+
+/// Every ordered pair as a flat row, ready for `pandas.DataFrame(rows)`:
+/// `source`, `dest`, `overall`, `difficulty`, then one column per dimension.
+#[pyfunction]
+#[pyo3(signature = (weights = None, profile = None))]
+fn matrix<'py>(
+    py: Python<'py>,
+    weights: Option<HashMap<String, f64>>,
+    profile: Option<&str>,
+) -> PyResult<Bound<'py, PyList>> {
+    let weights = resolve_weights(profile, weights)?;
+    let ecosystems = load_ecosystems();
+    let rows = PyList::empty(py);
+
+    for src in &ecosystems {
+        for dst in ecosystems.iter().filter(|d| d.id != src.id) {
+            let r = compute_migration_weighted(src, dst, None, None, &weights);
+            let row = PyDict::new(py);
+            row.set_item("source", &src.id)?;
+            row.set_item("dest", &dst.id)?;
+            row.set_item("overall", r.overall)?;
+            row.set_item("difficulty", &r.difficulty_label)?;
+            for d in &r.dimensions {
+                row.set_item(d.dimension.key(), d.score)?;
+            }
+            rows.append(row)?;
+        }
+    }
+    Ok(rows)
+}
+
+#[pymodule]
+fn bc_techmap(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("DATASET_VERSION", DATASET_VERSION)?;
+    m.add_function(wrap_pyfunction!(ecosystems, m)?)?;
+    m.add_function(wrap_pyfunction!(ecosystem, m)?)?;
+    m.add_function(wrap_pyfunction!(weight_profiles, m)?)?;
+    m.add_function(wrap_pyfunction!(weight_profile, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_migration, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix, m)?)?;
+    Ok(())
+}
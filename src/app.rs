@@ -3,15 +3,32 @@ use leptos::wasm_bindgen::prelude::*;
 use leptos::wasm_bindgen::JsCast;
 
 use crate::components::canvas::Canvas;
+use crate::components::command_palette::{Command, CommandPalette};
+use crate::components::dataset_menu::DatasetMenu;
+use crate::components::ecosystem_editor::EcosystemEditor;
+use crate::components::filter_bar::FilterBar;
+use crate::components::history_sidebar::HistorySidebar;
+use crate::components::map_view::MapView;
 use crate::components::migration_panel::MigrationPanel;
 use crate::components::mobile_view::MobileView;
 use crate::components::ring_view::RingView;
-use crate::data::ecosystem::{load_ecosystems, Ecosystem};
+use crate::components::weights_control::WeightsControl;
+use crate::components::whats_new::WhatsNew;
+use crate::custom;
+use crate::dataset;
+use crate::focus;
+use crate::history::{self, HistoryEntry};
+use crate::notes;
+use bc_techmap_core::data::ecosystem::load_ecosystems;
+use bc_techmap_core::data::scoring::Weights;
+use bc_techmap_core::export::{data_url, matrix_csv};
+use bc_techmap_core::filter::Filter;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum ViewMode {
     Grid,
     Ring,
+    Map,
 }
 
 #[derive(Clone, PartialEq)]
@@ -24,14 +41,69 @@ pub enum AppState {
     },
 }
 
+fn url_query() -> String {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .unwrap_or_default()
+}
+
+/// Follow a download link, as if it were clicked. Does nothing if it isn't
+/// on the page (e.g. no comparison is open).
+fn click_link(selector: &str) {
+    let link = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.query_selector(selector).ok().flatten())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+    if let Some(link) = link {
+        link.click();
+    }
+}
+
+/// Replace the query string without adding a history entry.
+fn set_url_query(query: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Ok(path) = window.location().pathname() else {
+        return;
+    };
+    let url = if query.is_empty() {
+        path
+    } else {
+        format!("{}?{}", path, query)
+    };
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
-    let ecosystems: Vec<Ecosystem> = load_ecosystems();
-    let ecosystems_stored = StoredValue::new(ecosystems.clone());
+    // The bundled dataset, or one imported in the browser, with overlays
+    // applied, plus any ecosystems added in the editor.
+    let bundled = StoredValue::new(load_ecosystems());
+    let (dataset, set_dataset) = signal(dataset::load());
+    Effect::new(move |_| dataset.with(dataset::save));
+    let (custom, set_custom) =
+        signal(dataset.with_untracked(|d| bundled.with_value(|b| custom::load(&d.effective(b)))));
+    Effect::new(move |_| custom.with(|c| custom::save(c)));
+    let ecosystems = Memo::new(move |_| {
+        let mut all = dataset.with(|d| bundled.with_value(|b| d.effective(b)));
+        all.extend(custom.get());
+        all
+    });
 
     let (state, set_state) = signal(AppState::Idle);
     let (mouse_pos, set_mouse_pos) = signal((0.0_f64, 0.0_f64));
     let (view_mode, set_view_mode) = signal(ViewMode::Grid);
+    let (weights, set_weights) = signal(Weights::default());
+    let (filter, set_filter) = signal(Filter::from_query(&url_query()));
+
+    // Keep the filter in the URL so a filtered view can be shared.
+    Effect::new(move |_| {
+        let query = filter.with(Filter::to_query);
+        set_url_query(&query);
+    });
 
     // Viewport width signal for mobile detection
     let (viewport_w, set_viewport_w) = signal(0.0_f64);
@@ -54,6 +126,92 @@ pub fn App() -> impl IntoView {
 
     let is_mobile = Signal::derive(move || viewport_w.get() < 768.0);
 
+    let (palette_open, set_palette_open) = signal(false);
+
+    let (editor_open, set_editor_open) = signal(false);
+
+    let (history, set_history) = signal(history::load());
+    let (history_open, set_history_open) = signal(false);
+    Effect::new(move |_| history.with(history::save));
+    // Deploy modes for the next panel to open with, set when restoring.
+    let restored = StoredValue::new(None::<HistoryEntry>);
+    // The entry being restored, until a panel shows it with its settings.
+    let restoring = StoredValue::new(None::<HistoryEntry>);
+
+    let restore = move |index: usize| {
+        let mut entry = None;
+        set_history.update(|h| entry = h.go(index));
+        let Some(entry) = entry else {
+            return;
+        };
+        restored.set_value(Some(entry.clone()));
+        restoring.set_value(Some(entry.clone()));
+        set_state.set(AppState::ShowResults {
+            source_id: entry.source.clone(),
+            dest_id: entry.dest.clone(),
+        });
+        set_weights.set(entry.weights);
+    };
+    let (notes, set_notes) = signal(notes::load());
+    Effect::new(move |_| notes.with(notes::save));
+
+    // Skip views from a panel that is being replaced: one for a pair no
+    // longer selected, or for the restored pair before its settings apply.
+    let on_view = move |entry: HistoryEntry| {
+        let shown = matches!(
+            state.get_untracked(),
+            AppState::ShowResults { ref source_id, ref dest_id }
+                if *source_id == entry.source && *dest_id == entry.dest
+        );
+        if !shown {
+            return;
+        }
+        let pending = restoring.with_value(|r| {
+            r.as_ref()
+                .is_some_and(|r| r.same_pair(&entry) && !r.same_settings(&entry))
+        });
+        if pending {
+            return;
+        }
+        restoring.set_value(None);
+        set_history.update(|h| h.record(entry));
+    };
+
+    // Ctrl/Cmd-K for the palette; arrow keys, Enter/Space and the focus trap.
+    let _ = window_event_listener(leptos::ev::keydown, move |ev| {
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case("k") {
+            ev.prevent_default();
+            set_palette_open.update(|o| *o = !*o);
+        } else {
+            focus::handle_keydown(&ev);
+        }
+    });
+
+    let on_command = move |command: Command| match command {
+        Command::Compare { source, dest } => {
+            set_state.set(AppState::ShowResults {
+                source_id: source,
+                dest_id: dest,
+            });
+        }
+        Command::Select { view, source } => {
+            if let Some(view) = view {
+                set_view_mode.set(view);
+            }
+            set_state.set(AppState::SourceSelected { source_id: source });
+        }
+        Command::View(view) => set_view_mode.set(view),
+        Command::Matrix => click_link(".header-export"),
+        Command::Export(format) => {
+            click_link(&format!(".export-menu a[download$=\".{}\"]", format));
+        }
+        Command::Weights(name) => {
+            if let Some(w) = Weights::profile(name) {
+                set_weights.set(w);
+            }
+        }
+    };
+
     let on_card_click = move |eco_id: String| {
         let current = state.get();
         match current {
@@ -76,9 +234,9 @@ pub fn App() -> impl IntoView {
                 ref source_id,
                 ref dest_id,
             } => {
-                // Ring mode and mobile need special dest-switching behavior
+                // Ring, map and mobile need special dest-switching behavior
                 let mode = view_mode.get_untracked();
-                if mode == ViewMode::Ring || is_mobile.get_untracked() {
+                if mode != ViewMode::Grid || is_mobile.get_untracked() {
                     if eco_id == *source_id {
                         set_state.set(AppState::Idle);
                     } else if eco_id == *dest_id {
@@ -136,6 +294,8 @@ pub fn App() -> impl IntoView {
                     "Tap an ecosystem to start".to_string()
                 } else if mode == ViewMode::Ring {
                     "Click an ecosystem to center it".to_string()
+                } else if mode == ViewMode::Map {
+                    "Nearby ecosystems are easier to migrate between".to_string()
                 } else {
                     "Click an ecosystem to start".to_string()
                 }
@@ -170,10 +330,18 @@ pub fn App() -> impl IntoView {
             ref dest_id,
         } = state.get()
         {
-            ecosystems_stored.with_value(|ecos| {
+            let mut modes = (None, None);
+            restored.update_value(|r| {
+                if let Some(e) = r.take() {
+                    if e.source == *source_id && e.dest == *dest_id {
+                        modes = (e.src_mode, e.dst_mode);
+                    }
+                }
+            });
+            ecosystems.with(|ecos| {
                 let src = ecos.iter().find(|e| e.id == *source_id).cloned();
                 let dst = ecos.iter().find(|e| e.id == *dest_id).cloned();
-                src.zip(dst)
+                src.zip(dst).map(|(src, dst)| (src, dst, modes))
             })
         } else {
             None
@@ -196,9 +364,18 @@ pub fn App() -> impl IntoView {
         }
     };
 
-    let ecosystems_grid = ecosystems.clone();
-    let ecosystems_ring = ecosystems.clone();
-    let ecosystems_mobile = ecosystems.clone();
+    let map_btn_class = move || {
+        if view_mode.get() == ViewMode::Map {
+            "view-btn active"
+        } else {
+            "view-btn"
+        }
+    };
+
+    let matrix_href = move || {
+        let csv = ecosystems.with(|ecos| weights.with(|w| matrix_csv(ecos, w)));
+        data_url("text/csv", &csv)
+    };
 
     let header_title = move || {
         if is_mobile.get() {
@@ -235,35 +412,102 @@ pub fn App() -> impl IntoView {
                     >
                         "RING"
                     </button>
+                    <button
+                        class=map_btn_class
+                        on:click=move |_| set_view_mode.set(ViewMode::Map)
+                    >
+                        "MAP"
+                    </button>
+                    <WeightsControl weights=weights set_weights=set_weights />
+                    <button
+                        class=move || if history_open.get() { "view-btn active" } else { "view-btn" }
+                        aria-pressed=move || history_open.get().to_string()
+                        on:click=move |_| set_history_open.update(|o| *o = !*o)
+                    >
+                        "HISTORY"
+                    </button>
+                    <DatasetMenu
+                        bundled=bundled
+                        ecosystems=ecosystems
+                        dataset=dataset
+                        set_dataset=set_dataset
+                        custom=custom
+                    />
+                    <button
+                        class=move || if editor_open.get() { "view-btn active" } else { "view-btn" }
+                        on:click=move |_| set_editor_open.set(true)
+                    >
+                        "CUSTOM"
+                    </button>
+                    <button
+                        class="view-btn"
+                        title="Command palette (Ctrl/Cmd-K)"
+                        on:click=move |_| set_palette_open.set(true)
+                    >
+                        "⌘K"
+                    </button>
                 </div>
-                <span class="header-hint">{hint_text}</span>
+                {move || view! {
+                    <FilterBar
+                        ecosystems=ecosystems.get()
+                        filter=filter
+                        set_filter=set_filter
+                    />
+                }}
+                <a
+                    class="header-export"
+                    href=matrix_href
+                    download="bc-techmap-matrix.csv"
+                    style=move || if is_mobile.get() { "display: none;" } else { "" }
+                >
+                    "CSV"
+                </a>
+                <span class="header-hint" aria-live="polite">{hint_text}</span>
             </div>
 
             {move || {
                 if is_mobile.get() {
                     view! {
                         <MobileView
-                            ecosystems=ecosystems_mobile.clone()
+                            ecosystems=ecosystems.get()
                             state=state
+                            filter=filter
                             on_card_click=on_card_click
                         />
                     }.into_any()
                 } else if view_mode.get() == ViewMode::Grid {
                     view! {
                         <Canvas
-                            ecosystems=ecosystems_grid.clone()
+                            ecosystems=ecosystems.get()
                             state=state
                             mouse_pos=mouse_pos
+                            viewport_w=viewport_w
+                            filter=filter
+                            notes=notes
                             on_card_click=on_card_click
                             on_canvas_click=on_canvas_click
                             on_mouse_move=on_mouse_move
                         />
                     }.into_any()
-                } else {
+                } else if view_mode.get() == ViewMode::Ring {
                     view! {
                         <RingView
-                            ecosystems=ecosystems_ring.clone()
+                            ecosystems=ecosystems.get()
                             state=state
+                            weights=weights
+                            filter=filter
+                            notes=notes
+                            on_card_click=on_card_click
+                            on_canvas_click=on_canvas_click
+                        />
+                    }.into_any()
+                } else {
+                    view! {
+                        <MapView
+                            ecosystems=ecosystems.get()
+                            state=state
+                            weights=weights
+                            filter=filter
                             on_card_click=on_card_click
                             on_canvas_click=on_canvas_click
                         />
@@ -273,12 +517,20 @@ pub fn App() -> impl IntoView {
 
             {move || {
                 if show_panel() {
-                    panel_data().map(|(src, dst)| {
+                    panel_data().map(|(src, dst, (src_mode, dst_mode))| {
                         view! {
                             <MigrationPanel
                                 source=src
                                 dest=dst
+                                weights=weights
+                                bundled=bundled
+                                ecosystems=ecosystems
                                 on_close=on_close_panel
+                                initial_src_mode=src_mode
+                                initial_dst_mode=dst_mode
+                                on_view=on_view
+                                notes=notes
+                                set_notes=set_notes
                             />
                         }
                     })
@@ -287,6 +539,34 @@ pub fn App() -> impl IntoView {
                 }
             }}
 
+            <Show when=move || history_open.get() && !is_mobile.get()>
+                <HistorySidebar
+                    ecosystems=ecosystems.get()
+                    history=history
+                    set_history=set_history
+                    on_restore=restore
+                />
+            </Show>
+
+            <Show when=move || editor_open.get()>
+                <EcosystemEditor
+                    ecosystems=ecosystems
+                    custom=custom
+                    set_custom=set_custom
+                    set_open=set_editor_open
+                />
+            </Show>
+
+            <Show when=move || palette_open.get()>
+                <CommandPalette
+                    ecosystems=ecosystems.get()
+                    set_open=set_palette_open
+                    on_command=on_command
+                />
+            </Show>
+
+            <WhatsNew />
+
             <div class="disclaimer">
                 "Disclaimer: The information presented may not be current or accurate. Any decisions should be based on your own independent research."
             </div>
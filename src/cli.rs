@@ -0,0 +1,436 @@
+//! Command-line interface for the native build. The wasm build mounts the
+//! web app instead; see `main`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bc_techmap_core::api::{deploy_mode, write_static_api};
+use bc_techmap_core::data::dataset::read_dataset;
+use bc_techmap_core::data::ecosystem::{load_ecosystems, Ecosystem};
+use bc_techmap_core::data::effort::ProjectProfile;
+use bc_techmap_core::data::plan::generate_plan;
+use bc_techmap_core::data::provenance::{stale_dataset, stale_dimensions};
+use bc_techmap_core::data::scoring::{compute_migration, Weights, DIFFICULTY_LABELS};
+use bc_techmap_core::diff::{diff_datasets, diff_text};
+use bc_techmap_core::export::{
+    matrix_csv, pairs_csv, plan_markdown_issues, report_json, report_markdown, ReportExport,
+    ReportInputs,
+};
+use bc_techmap_core::graph::{
+    difficulty_band_named, difficulty_graph, graph_dot, graph_gexf, graph_mermaid, GraphOptions,
+};
+use bc_techmap_core::render::{report_html, report_svg};
+
+const USAGE: &str = "\
+Usage: bc-techmap <command> [options]
+
+Commands:
+  list                          List ecosystem ids
+  export <source> <dest>        Export a migration report
+      --format md|json          Output format (default: md)
+      --src-mode <mode>         Source deploy mode
+      --dst-mode <mode>         Destination deploy mode
+      --contracts <n>           Contracts in the project
+      --loc <n>                 Lines of code
+      --team <n>                Team size
+      --coverage <pct>          Test coverage, 0-100
+  plan <source> <dest>          Export the migration plan as Markdown issues
+      --src-mode, --dst-mode    As for export
+  render <source> <dest>        Render a printable report
+      --format svg|html         Output format (default: svg)
+      --out <file>              Default: report-<source>-<dest>.<format>
+      (also accepts the export options)
+  api --out <dir>               Write the static JSON API to a directory
+  matrix                        Export all pairs as CSV
+      --pairs                   One row per pair with dimension scores
+  graph                         Export the difficulty graph
+      --format dot|mermaid|gexf Output format (default: dot)
+      --max <difficulty>        Keep edges up to trivial|easy|moderate|hard|extreme
+                                (default: easy)
+      --modes                   One node per deploy mode
+      --mutual                  Only pairs within the threshold both ways
+  diff <old> [<new>]            Field and verdict changes between two datasets
+                                (files or git revisions; <new> defaults to
+                                the bundled dataset)
+      --format text|json        Output format (default: text)
+  stale [<source> <dest>]       Attributes not verified recently; for a pair,
+                                grouped by the dimension they feed
+      --max-age <days>          Default: 365
+      --format text|json        Output format (default: text)
+  tui                           Browse the map in the terminal
+  serve                         Run the JSON API as a local HTTP server
+      --port <n>                Default: 8080
+      --host <addr>             Default: 127.0.0.1
+
+Commands that print accept --out <file> to write to a file instead of stdout.";
+
+/// Parsed command line: positional arguments and `--flag [value]` options.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    fn parse(raw: impl Iterator<Item = String>) -> Self {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut raw = raw.peekable();
+
+        while let Some(arg) = raw.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = match raw.peek() {
+                    Some(next) if !next.starts_with("--") => raw.next().unwrap_or_default(),
+                    _ => String::new(),
+                };
+                options.insert(name.to_string(), value);
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        Args {
+            positional,
+            options,
+        }
+    }
+
+    fn opt(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|s| s.as_str())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.opt(name) {
+            None => Ok(default),
+            Some(v) => v
+                .parse()
+                .map_err(|_| format!("--{} expects a number, got {:?}", name, v)),
+        }
+    }
+}
+
+fn find<'a>(ecosystems: &'a [Ecosystem], id: &str) -> Result<&'a Ecosystem, String> {
+    ecosystems
+        .iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| format!("unknown ecosystem {:?} (see `bc-techmap list`)", id))
+}
+
+/// A source → dest pair named on the command line.
+struct Pair<'a> {
+    src: &'a Ecosystem,
+    dst: &'a Ecosystem,
+    src_mode: Option<String>,
+    dst_mode: Option<String>,
+}
+
+fn pair<'a>(args: &Args, ecosystems: &'a [Ecosystem]) -> Result<Pair<'a>, String> {
+    let [src_id, dst_id] = &args.positional[..] else {
+        return Err("expected <source> <dest>".to_string());
+    };
+    let src = find(ecosystems, src_id)?;
+    let dst = find(ecosystems, dst_id)?;
+    Ok(Pair {
+        src,
+        dst,
+        src_mode: deploy_mode(src, args.opt("src-mode"))?,
+        dst_mode: deploy_mode(dst, args.opt("dst-mode"))?,
+    })
+}
+
+fn project(args: &Args) -> Result<ProjectProfile, String> {
+    let defaults = ProjectProfile::default();
+    let coverage: f64 = args.number("coverage", defaults.test_coverage * 100.0)?;
+    Ok(ProjectProfile {
+        contracts: args.number("contracts", defaults.contracts)?,
+        loc: args.number("loc", defaults.loc)?,
+        team_size: args.number::<u32>("team", defaults.team_size)?.max(1),
+        test_coverage: (coverage / 100.0).clamp(0.0, 1.0),
+    })
+}
+
+fn report_export(args: &Args, ecosystems: &[Ecosystem]) -> Result<ReportExport, String> {
+    let p = pair(args, ecosystems)?;
+    let inputs = ReportInputs {
+        source: p.src.clone(),
+        dest: p.dst.clone(),
+        src_mode: p.src_mode,
+        dst_mode: p.dst_mode,
+        project: project(args)?,
+        weights: Weights::default(),
+        notes: None,
+    };
+    Ok(ReportExport::new(inputs, &load_ecosystems()))
+}
+
+fn export(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let export = report_export(args, ecosystems)?;
+
+    match args.opt("format").unwrap_or("md") {
+        "md" | "markdown" => Ok(report_markdown(&export)),
+        "json" => Ok(report_json(&export)),
+        other => Err(format!("unknown format {:?} (expected md or json)", other)),
+    }
+}
+
+fn render(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let export = report_export(args, ecosystems)?;
+    let format = args.opt("format").unwrap_or("svg");
+    let output = match format {
+        "svg" => report_svg(&export),
+        "html" => report_html(&export),
+        other => return Err(format!("unknown format {:?} (expected svg or html)", other)),
+    };
+
+    let path = args.opt("out").map(str::to_string).unwrap_or_else(|| {
+        format!(
+            "report-{}-{}.{}",
+            export.inputs.source.id, export.inputs.dest.id, format
+        )
+    });
+    fs::write(&path, output).map_err(|e| format!("write {}: {}", path, e))?;
+    Ok(format!("wrote {}\n", path))
+}
+
+fn plan(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let p = pair(args, ecosystems)?;
+    let (src_mode, dst_mode) = (p.src_mode.as_deref(), p.dst_mode.as_deref());
+    let report = compute_migration(p.src, p.dst, src_mode, dst_mode);
+    let plan = generate_plan(&report, p.src, p.dst, src_mode, dst_mode);
+    Ok(plan_markdown_issues(&plan, p.src, p.dst))
+}
+
+fn api(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let dir = args.opt("out").ok_or("api requires --out <dir>")?;
+    let written = write_static_api(Path::new(dir), ecosystems)
+        .map_err(|e| format!("write {}: {}", dir, e))?;
+    Ok(format!("wrote {} files to {}\n", written, dir))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn serve(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let host = args.opt("host").unwrap_or("127.0.0.1");
+    let port: u16 = args.number("port", 8080)?;
+    crate::server::serve(&format!("{}:{}", host, port), ecosystems)?;
+    Ok(String::new())
+}
+
+fn graph(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let max = args.opt("max").unwrap_or("easy");
+    let options = GraphOptions {
+        max_band: difficulty_band_named(max).ok_or_else(|| {
+            format!(
+                "unknown difficulty {:?} (expected {})",
+                max,
+                DIFFICULTY_LABELS.join(", ").to_lowercase()
+            )
+        })?,
+        per_mode: args.flag("modes"),
+        mutual: args.flag("mutual"),
+    };
+    let graph = difficulty_graph(ecosystems, &options);
+
+    match args.opt("format").unwrap_or("dot") {
+        "dot" => Ok(graph_dot(&graph)),
+        "mermaid" => Ok(graph_mermaid(&graph)),
+        "gexf" => Ok(graph_gexf(&graph)),
+        other => Err(format!(
+            "unknown format {:?} (expected dot, mermaid or gexf)",
+            other
+        )),
+    }
+}
+
+/// Where the bundled dataset lives in the repository, for git revisions;
+/// older revisions kept it at the top level.
+const DATASET_PATHS: [&str; 2] = ["crates/core/data/ecosystems.toml", "data/ecosystems.toml"];
+
+/// `ecosystems.toml` at a git revision.
+fn git_dataset(rev: &str) -> Result<String, String> {
+    let mut error = String::new();
+    for path in DATASET_PATHS {
+        let output = Command::new("git")
+            .args(["show", &format!("{}:{}", rev, path)])
+            .output()
+            .map_err(|e| format!("run git: {}", e))?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        if error.is_empty() {
+            error = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        }
+    }
+    Err(format!(
+        "{:?} is neither a file nor a git revision with a dataset: {}",
+        rev, error
+    ))
+}
+
+/// A dataset file, or `ecosystems.toml` at a git revision.
+fn dataset_at(spec: &str) -> Result<Vec<Ecosystem>, String> {
+    let text = if Path::new(spec).is_file() {
+        fs::read_to_string(spec).map_err(|e| format!("read {}: {}", spec, e))?
+    } else {
+        git_dataset(spec)?
+    };
+    read_dataset(&text).map_err(|e| format!("{}: {}", spec, e))
+}
+
+fn diff(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let (old, new) = match &args.positional[..] {
+        [old] => (dataset_at(old)?, ecosystems.to_vec()),
+        [old, new] => (dataset_at(old)?, dataset_at(new)?),
+        _ => return Err("diff takes <old> [<new>]".to_string()),
+    };
+    let diff = diff_datasets(&old, &new, &Weights::default());
+
+    match args.opt("format").unwrap_or("text") {
+        "text" => Ok(diff_text(&diff)),
+        "json" => serde_json::to_string_pretty(&diff)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        other => Err(format!(
+            "unknown format {:?} (expected text or json)",
+            other
+        )),
+    }
+}
+
+fn stale(args: &Args, ecosystems: &[Ecosystem]) -> Result<String, String> {
+    let max_age: i64 = args.number("max-age", 365)?;
+    let today = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs() as i64
+        / 86_400;
+    let json = match args.opt("format").unwrap_or("text") {
+        "text" => false,
+        "json" => true,
+        other => {
+            return Err(format!(
+                "unknown format {:?} (expected text or json)",
+                other
+            ))
+        }
+    };
+
+    if args.positional.is_empty() {
+        let fields = stale_dataset(ecosystems, today, max_age);
+        if json {
+            return serde_json::to_string_pretty(&fields)
+                .map(|j| j + "\n")
+                .map_err(|e| e.to_string());
+        }
+        if fields.is_empty() {
+            return Ok(format!(
+                "Every attribute was verified in the last {} days.\n",
+                max_age
+            ));
+        }
+        return Ok(fields.iter().map(|f| f.describe() + "\n").collect());
+    }
+
+    let p = pair(args, ecosystems)?;
+    let report = compute_migration(p.src, p.dst, p.src_mode.as_deref(), p.dst_mode.as_deref());
+    let dimensions = stale_dimensions(p.src, p.dst, today, max_age);
+    if json {
+        let list: Vec<serde_json::Value> = dimensions
+            .iter()
+            .map(|(dim, fields)| serde_json::json!({ "dimension": dim.key(), "fields": fields }))
+            .collect();
+        return serde_json::to_string_pretty(&list)
+            .map(|j| j + "\n")
+            .map_err(|e| e.to_string());
+    }
+
+    let mut out = format!(
+        "{} → {}: {:.1}/5 ({})\n",
+        p.src.name,
+        p.dst.name,
+        report.overall * 5.0,
+        report.difficulty_label
+    );
+    if dimensions.is_empty() {
+        out.push_str(&format!(
+            "Every attribute behind it was verified in the last {} days.\n",
+            max_age
+        ));
+    }
+    for (dim, fields) in &dimensions {
+        let label = report
+            .dimensions
+            .iter()
+            .find(|d| d.dimension == *dim)
+            .map(|d| d.label.clone())
+            .unwrap_or_default();
+        out.push_str(&format!("\n{} ({})\n", dim.name(), label));
+        for f in fields {
+            out.push_str(&format!("  {}\n", f.describe()));
+        }
+    }
+    Ok(out)
+}
+
+fn list(ecosystems: &[Ecosystem]) -> String {
+    ecosystems
+        .iter()
+        .map(|e| {
+            format!(
+                "{:<12} {} [{}]\n",
+                e.id,
+                e.name,
+                e.deployment_options.join(", ")
+            )
+        })
+        .collect()
+}
+
+fn run_command(command: &str, args: &Args) -> Result<String, String> {
+    let ecosystems = load_ecosystems();
+    match command {
+        "list" => Ok(list(&ecosystems)),
+        "export" => export(args, &ecosystems),
+        "plan" => plan(args, &ecosystems),
+        "render" => render(args, &ecosystems),
+        "api" => api(args, &ecosystems),
+        #[cfg(not(target_arch = "wasm32"))]
+        "serve" => serve(args, &ecosystems),
+        #[cfg(not(target_arch = "wasm32"))]
+        "tui" => crate::tui::run().map(|_| String::new()),
+        "graph" => graph(args, &ecosystems),
+        "diff" => diff(args, &ecosystems),
+        "stale" => stale(args, &ecosystems),
+        "matrix" if args.flag("pairs") => Ok(pairs_csv(&ecosystems, &Weights::default())),
+        "matrix" => Ok(matrix_csv(&ecosystems, &Weights::default())),
+        "help" => Ok(format!("{}\n", USAGE)),
+        other => Err(format!("unknown command {:?}\n\n{}", other, USAGE)),
+    }
+}
+
+pub fn run() {
+    let mut raw = std::env::args().skip(1);
+    let command = raw.next().unwrap_or_else(|| "help".to_string());
+    let args = Args::parse(raw);
+
+    let result = run_command(&command, &args).and_then(|output| match args.opt("out") {
+        // These write their own files, or nothing at all.
+        Some(path) if !matches!(command.as_str(), "render" | "api" | "tui" | "serve") => {
+            fs::write(path, output).map_err(|e| format!("write {}: {}", path, e))
+        }
+        _ => {
+            print!("{}", output);
+            Ok(())
+        }
+    });
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -1,9 +1,10 @@
 use leptos::prelude::*;
 
-use crate::theme;
+use bc_techmap_core::layout::CARD_SIZE;
+use bc_techmap_core::theme;
 
-pub const CARD_WIDTH: f64 = 180.0;
-pub const CARD_HEIGHT: f64 = 130.0;
+pub const CARD_WIDTH: f64 = CARD_SIZE[0];
+pub const CARD_HEIGHT: f64 = CARD_SIZE[1];
 
 pub fn card_center(pos: &[f64; 2]) -> (f64, f64) {
     (pos[0] + CARD_WIDTH / 2.0, pos[1] + CARD_HEIGHT / 2.0)
@@ -4,22 +4,41 @@ use leptos::wasm_bindgen::JsCast;
 use crate::app::AppState;
 use crate::components::arrow::{Arrow, FixedArrow};
 use crate::components::ecosystem_card::EcosystemCard;
-use crate::data::ecosystem::Ecosystem;
+use crate::notes::Notes;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::filter::Filter;
+use bc_techmap_core::layout::{grid_height, grid_layout};
 
 #[component]
 pub fn Canvas(
     ecosystems: Vec<Ecosystem>,
     state: ReadSignal<AppState>,
     mouse_pos: ReadSignal<(f64, f64)>,
+    viewport_w: ReadSignal<f64>,
+    filter: ReadSignal<Filter>,
+    notes: ReadSignal<Notes>,
     #[prop(into)] on_card_click: Callback<String>,
     on_canvas_click: impl Fn() + 'static + Copy,
     on_mouse_move: impl Fn(f64, f64) + 'static + Copy,
 ) -> impl IntoView {
     let ecosystems_for_arrow = ecosystems.clone();
     let ecosystems_for_fixed = ecosystems.clone();
+    let ecosystems_for_layout = ecosystems.clone();
 
+    let positions = Memo::new(move |_| grid_layout(&ecosystems_for_layout, viewport_w.get()));
+    let position_of = move |index: usize| positions.with(|p| p[index]);
+    let content_style = move || format!("height: {}px;", positions.with(|p| grid_height(p)));
+
+    // Content coordinates: the canvas scrolls when the grid is taller than the window.
     let handle_mousemove = move |ev: web_sys::MouseEvent| {
-        on_mouse_move(ev.client_x() as f64, (ev.client_y() - 48) as f64);
+        let scroll_top = ev
+            .current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .map_or(0, |el| el.scroll_top());
+        on_mouse_move(
+            ev.client_x() as f64,
+            (ev.client_y() - 48 + scroll_top) as f64,
+        );
     };
 
     let handle_click = move |ev: web_sys::MouseEvent| {
@@ -36,71 +55,89 @@ pub fn Canvas(
             on:mousemove=handle_mousemove
             on:click=handle_click
         >
-            {ecosystems
-                .iter()
-                .map(|eco| {
-                    let eco_clone = eco.clone();
-                    let eco_id = eco.id.clone();
-                    let is_selected = Signal::derive(move || {
-                        match state.get() {
-                            AppState::SourceSelected { ref source_id } => *source_id == eco_id,
-                            AppState::ShowResults { ref source_id, ref dest_id, .. } => {
-                                *source_id == eco_id || *dest_id == eco_id
+            <div class="canvas-content" style=content_style>
+                {ecosystems
+                    .iter()
+                    .enumerate()
+                    .map(|(index, eco)| {
+                        let eco_clone = eco.clone();
+                        let eco_id = eco.id.clone();
+                        let eco_for_filter = eco.clone();
+                        let dimmed =
+                            Signal::derive(move || !filter.with(|f| f.matches(&eco_for_filter)));
+                        let notes_id = eco.id.clone();
+                        let has_notes = Signal::derive(move || {
+                            let source = match state.get() {
+                                AppState::SourceSelected { source_id }
+                                | AppState::ShowResults { source_id, .. } => Some(source_id),
+                                AppState::Idle => None,
+                            };
+                            notes.with(|n| n.marks(&notes_id, source.as_deref()))
+                        });
+                        let is_selected = Signal::derive(move || {
+                            match state.get() {
+                                AppState::SourceSelected { ref source_id } => *source_id == eco_id,
+                                AppState::ShowResults { ref source_id, ref dest_id, .. } => {
+                                    *source_id == eco_id || *dest_id == eco_id
+                                }
+                                _ => false,
                             }
-                            _ => false,
+                        });
+                        view! {
+                            <EcosystemCard
+                                ecosystem=eco_clone
+                                is_selected=is_selected
+                                on_click=on_card_click
+                                position=Signal::derive(move || position_of(index))
+                                dimmed=dimmed
+                                has_notes=has_notes
+                            />
                         }
-                    });
-                    view! {
-                        <EcosystemCard
-                            ecosystem=eco_clone
-                            is_selected=is_selected
-                            on_click=on_card_click
-                        />
-                    }
-                })
-                .collect::<Vec<_>>()}
+                    })
+                    .collect::<Vec<_>>()}
 
-            {move || {
-                let st = state.get();
-                match st {
-                    AppState::SourceSelected { ref source_id } => {
-                        let source = ecosystems_for_arrow
-                            .iter()
-                            .find(|e| e.id == *source_id);
-                        if let Some(src) = source {
-                            let (mx, my) = mouse_pos.get();
-                            Some(view! {
-                                <Arrow
-                                    source_pos=src.position
-                                    end_x=mx
-                                    end_y=my
-                                />
-                            }.into_any())
-                        } else {
-                            None
+                {move || {
+                    let st = state.get();
+                    match st {
+                        AppState::SourceSelected { ref source_id } => {
+                            let source = ecosystems_for_arrow
+                                .iter()
+                                .position(|e| e.id == *source_id);
+                            if let Some(src) = source {
+                                let (mx, my) = mouse_pos.get();
+                                Some(view! {
+                                    <Arrow
+                                        source_pos=position_of(src)
+                                        end_x=mx
+                                        end_y=my
+                                    />
+                                }.into_any())
+                            } else {
+                                None
+                            }
                         }
-                    }
-                    AppState::ShowResults { ref source_id, ref dest_id, .. } => {
-                        let source = ecosystems_for_fixed
-                            .iter()
-                            .find(|e| e.id == *source_id);
-                        let dest = ecosystems_for_fixed
-                            .iter()
-                            .find(|e| e.id == *dest_id);
-                        if let (Some(src), Some(dst)) = (source, dest) {
-                            Some(view! {
-                                <FixedArrow
-                                    source_pos=src.position
-                                    dest_pos=dst.position
-                                />
-                            }.into_any())
-                        } else {
-                            None
+                        AppState::ShowResults { ref source_id, ref dest_id, .. } => {
+                            let source = ecosystems_for_fixed
+                                .iter()
+                                .position(|e| e.id == *source_id);
+                            let dest = ecosystems_for_fixed
+                                .iter()
+                                .position(|e| e.id == *dest_id);
+                            if let (Some(src), Some(dst)) = (source, dest) {
+                                Some(view! {
+                                    <FixedArrow
+                                        source_pos=position_of(src)
+                                        dest_pos=position_of(dst)
+                                    />
+                                }.into_any())
+                            } else {
+                                None
+                            }
                         }
+                        _ => None,
                     }
-                    _ => None,
-                }
-            }}
+                }}
+            </div>
         </div>
     }
 }
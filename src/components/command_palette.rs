@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::app::ViewMode;
+use crate::focus;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::scoring::WEIGHT_PROFILES;
+use bc_techmap_core::filter::fuzzy_score;
+
+const MAX_SUGGESTIONS: usize = 8;
+const EXPORT_FORMATS: [&str; 4] = ["md", "json", "svg", "html"];
+const VIEWS: [(&str, ViewMode); 3] = [
+    ("grid", ViewMode::Grid),
+    ("ring", ViewMode::Ring),
+    ("map", ViewMode::Map),
+];
+
+/// What a palette entry does; the app runs it through the same state
+/// transitions as clicks.
+#[derive(Clone, PartialEq)]
+pub enum Command {
+    Compare {
+        source: String,
+        dest: String,
+    },
+    Select {
+        view: Option<ViewMode>,
+        source: String,
+    },
+    View(ViewMode),
+    /// Download the difficulty matrix CSV.
+    Matrix,
+    /// Export the open comparison in a format from `EXPORT_FORMATS`.
+    Export(&'static str),
+    Weights(&'static str),
+}
+
+#[derive(Clone, PartialEq)]
+struct Suggestion {
+    command: Command,
+    label: String,
+}
+
+fn view_name(view: ViewMode) -> &'static str {
+    VIEWS
+        .iter()
+        .find(|(_, v)| *v == view)
+        .map_or("", |(n, _)| n)
+}
+
+/// Ecosystems matching `query` on name, short name or id, best first.
+fn rank<'a>(query: &str, ecosystems: &'a [Ecosystem]) -> Vec<&'a Ecosystem> {
+    let mut scored: Vec<(i32, usize)> = ecosystems
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| {
+            let score = [&e.name, &e.short, &e.id]
+                .iter()
+                .filter_map(|text| fuzzy_score(query, text))
+                .max()?;
+            Some((score, i))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().map(|(_, i)| &ecosystems[i]).collect()
+}
+
+/// Split "eth → sol", "eth -> sol", "eth to sol" or "eth sol".
+fn split_pair(input: &str) -> Option<(&str, &str)> {
+    for sep in ["→", "->", " to "] {
+        if let Some((a, b)) = input.split_once(sep) {
+            return Some((a.trim(), b.trim()));
+        }
+    }
+    let (a, b) = input.split_once(char::is_whitespace)?;
+    Some((a.trim(), b.trim()))
+}
+
+fn keyword_commands(word: &str) -> Vec<Suggestion> {
+    let mut out = Vec::new();
+    for (name, view) in VIEWS {
+        if name.starts_with(word) {
+            out.push(Suggestion {
+                command: Command::View(view),
+                label: format!("Switch to the {} view", name),
+            });
+        }
+    }
+    if "matrix".starts_with(word) {
+        out.push(Suggestion {
+            command: Command::Matrix,
+            label: "Download the difficulty matrix (CSV)".to_string(),
+        });
+    }
+    if "export".starts_with(word) {
+        out.extend(export_commands(""));
+    }
+    if "weights".starts_with(word) {
+        out.extend(weight_commands(""));
+    }
+    out
+}
+
+fn export_commands(format: &str) -> Vec<Suggestion> {
+    EXPORT_FORMATS
+        .iter()
+        .filter(|f| f.starts_with(format))
+        .map(|&f| Suggestion {
+            command: Command::Export(f),
+            label: format!("Export the open comparison as {}", f.to_uppercase()),
+        })
+        .collect()
+}
+
+fn weight_commands(profile: &str) -> Vec<Suggestion> {
+    WEIGHT_PROFILES
+        .iter()
+        .filter(|p| p.starts_with(profile))
+        .map(|&p| Suggestion {
+            command: Command::Weights(p),
+            label: format!("Use the {} weights", p),
+        })
+        .collect()
+}
+
+fn suggestions(input: &str, ecosystems: &[Ecosystem]) -> Vec<Suggestion> {
+    let input = input.trim().to_lowercase();
+    let (word, rest) = input
+        .split_once(char::is_whitespace)
+        .map_or((input.as_str(), ""), |(w, r)| (w, r.trim()));
+
+    let mut out = match word {
+        "" => keyword_commands(""),
+        "matrix" => keyword_commands("matrix"),
+        "export" => export_commands(rest),
+        "weights" => weight_commands(rest),
+        _ => {
+            let mut out = Vec::new();
+            if let Some(&(name, view)) = VIEWS.iter().find(|(n, _)| *n == word) {
+                out = rank(rest, ecosystems)
+                    .into_iter()
+                    .map(|e| Suggestion {
+                        command: Command::Select {
+                            view: Some(view),
+                            source: e.id.clone(),
+                        },
+                        label: format!("{} {}", name, e.name),
+                    })
+                    .collect();
+                if rest.is_empty() {
+                    out.insert(0, keyword_commands(name).remove(0));
+                }
+            } else if let Some((a, b)) = split_pair(&input).filter(|(_, b)| !b.is_empty()) {
+                if let Some(src) = rank(a, ecosystems).first() {
+                    out = rank(b, ecosystems)
+                        .into_iter()
+                        .filter(|d| d.id != src.id)
+                        .map(|d| Suggestion {
+                            command: Command::Compare {
+                                source: src.id.clone(),
+                                dest: d.id.clone(),
+                            },
+                            label: format!("{} → {}", src.name, d.name),
+                        })
+                        .collect();
+                }
+            } else {
+                out = keyword_commands(word);
+                out.extend(rank(word, ecosystems).into_iter().map(|e| Suggestion {
+                    command: Command::Select {
+                        view: None,
+                        source: e.id.clone(),
+                    },
+                    label: format!("Select {} as source", e.name),
+                }));
+            }
+            out
+        }
+    };
+    out.truncate(MAX_SUGGESTIONS);
+    out
+}
+
+/// Ctrl/Cmd-K palette: type an ecosystem, a pair ("eth → sol") or a command
+/// ("ring sui", "matrix", "export md", "weights defi").
+#[component]
+pub fn CommandPalette(
+    ecosystems: Vec<Ecosystem>,
+    set_open: WriteSignal<bool>,
+    #[prop(into)] on_command: Callback<Command>,
+) -> impl IntoView {
+    let ecosystems = StoredValue::new(ecosystems);
+    let (input, set_input) = signal(String::new());
+    let (cursor, set_cursor) = signal(0_usize);
+
+    let items = Memo::new(move |_| {
+        input.with(|text| ecosystems.with_value(|ecos| suggestions(text, ecos)))
+    });
+
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+    let return_to = focus::focused_eco_id();
+    Effect::new(move |_| {
+        if let Some(el) = input_ref.get() {
+            let _ = el.focus();
+        }
+    });
+    on_cleanup(move || {
+        if let Some(id) = return_to {
+            set_timeout(move || focus::restore_focus(&id), Duration::ZERO);
+        }
+    });
+
+    let run = move |index: usize| {
+        if let Some(item) = items.with_untracked(|items| items.get(index).cloned()) {
+            set_open.set(false);
+            on_command.run(item.command);
+        }
+    };
+
+    let on_keydown = move |ev: web_sys::KeyboardEvent| {
+        let len = items.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" if len > 0 => {
+                ev.prevent_default();
+                set_cursor.update(|c| *c = (*c + 1) % len);
+            }
+            "ArrowUp" if len > 0 => {
+                ev.prevent_default();
+                set_cursor.update(|c| *c = (*c + len - 1) % len);
+            }
+            "Enter" => {
+                ev.prevent_default();
+                run(cursor.get_untracked());
+            }
+            "Escape" => {
+                // Close the palette only, not the comparison underneath.
+                ev.stop_propagation();
+                set_open.set(false);
+            }
+            _ => {}
+        }
+    };
+
+    let options = move || {
+        items
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let hint = match &item.command {
+                    Command::Compare { .. } => "compare",
+                    Command::Select { view: Some(v), .. } | Command::View(v) => view_name(*v),
+                    Command::Select { view: None, .. } => "select",
+                    Command::Matrix | Command::Export(_) => "export",
+                    Command::Weights(_) => "weights",
+                };
+                view! {
+                    <li
+                        class=move || {
+                            if cursor.get() == i { "palette-item active" } else { "palette-item" }
+                        }
+                        role="option"
+                        aria-selected=move || (cursor.get() == i).to_string()
+                        on:mouseenter=move |_| set_cursor.set(i)
+                        on:click=move |_| run(i)
+                    >
+                        <span class="palette-label">{item.label}</span>
+                        <span class="palette-hint">{hint}</span>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="palette-backdrop" on:click=move |_| set_open.set(false)>
+            <div
+                class="command-palette"
+                role="dialog"
+                aria-modal="true"
+                aria-label="Command palette"
+                on:click=|ev| ev.stop_propagation()
+            >
+                <input
+                    class="palette-input"
+                    type="text"
+                    placeholder="eth → sol, ring sui, matrix, export md, weights defi…"
+                    node_ref=input_ref
+                    role="combobox"
+                    aria-expanded="true"
+                    aria-controls="palette-list"
+                    prop:value=move || input.get()
+                    on:input=move |ev| {
+                        set_input.set(event_target_value(&ev));
+                        set_cursor.set(0);
+                    }
+                    on:keydown=on_keydown
+                />
+                <ul class="palette-list" id="palette-list" role="listbox">
+                    {options}
+                </ul>
+                <Show when=move || items.with(Vec::is_empty)>
+                    <div class="palette-empty">"No matches"</div>
+                </Show>
+            </div>
+        </div>
+    }
+}
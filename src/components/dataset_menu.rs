@@ -0,0 +1,247 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+
+use crate::custom::taken_ids;
+use crate::dataset::DatasetState;
+use bc_techmap_core::data::dataset::{
+    apply_overlay, dataset_json, dataset_release, dataset_toml, parse_dataset, parse_overlay,
+};
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::export::data_url;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ImportMode {
+    Replace,
+    Overlay,
+}
+
+/// Read the file picked in an `<input type="file">` as text.
+fn read_file(ev: &web_sys::Event, on_text: impl FnOnce(String) + 'static) {
+    let Some(input) = ev
+        .target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+    else {
+        return;
+    };
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return;
+    };
+    // Picking the same file again should import it again.
+    input.set_value("");
+    let Ok(reader) = web_sys::FileReader::new() else {
+        return;
+    };
+    let result = reader.clone();
+    let on_load = Closure::once_into_js(move || {
+        if let Some(text) = result.result().ok().and_then(|v| v.as_string()) {
+            on_text(text);
+        }
+    });
+    reader.set_onload(Some(on_load.unchecked_ref()));
+    let _ = reader.read_as_text(&file);
+}
+
+/// Header dropdown for importing a dataset or overlay file, typing overlay
+/// patches, and exporting the effective dataset.
+#[component]
+pub fn DatasetMenu(
+    bundled: StoredValue<Vec<Ecosystem>>,
+    /// Everything on the map, custom ecosystems included; this is exported.
+    ecosystems: Memo<Vec<Ecosystem>>,
+    dataset: ReadSignal<DatasetState>,
+    set_dataset: WriteSignal<DatasetState>,
+    /// The user's own ecosystems, whose ids a replacement or reset can't reuse.
+    custom: ReadSignal<Vec<Ecosystem>>,
+) -> impl IntoView {
+    let (open, set_open) = signal(false);
+    let (mode, set_mode) = signal(ImportMode::Overlay);
+    let (typed, set_typed) = signal(String::new());
+    let (message, set_message) = signal(String::new());
+
+    let effective = Memo::new(move |_| dataset.with(|d| bundled.with_value(|b| d.effective(b))));
+
+    // Check overlays against the current dataset before keeping them.
+    let add_overlay = move |text: String| {
+        let result = parse_overlay(&text).and_then(|patches| {
+            let mut ecos = effective.get_untracked();
+            apply_overlay(&mut ecos, &patches).map(|()| patches)
+        });
+        match result {
+            Ok(patches) => {
+                set_message.set(format!("Applied {} patch(es)", patches.len()));
+                set_dataset.update(|d| d.patches.extend(patches));
+                set_typed.set(String::new());
+            }
+            Err(e) => set_message.set(e),
+        }
+    };
+
+    let import = move |text: String| match mode.get_untracked() {
+        ImportMode::Overlay => add_overlay(text),
+        ImportMode::Replace => match parse_dataset(&text).and_then(|ecos| {
+            match custom.with_untracked(|c| taken_ids(c, &ecos)) {
+                None => Ok(ecos),
+                Some(taken) => Err(format!(
+                    "Ids already used by your own ecosystems: {}",
+                    taken
+                )),
+            }
+        }) {
+            Ok(ecos) => {
+                set_message.set(format!(
+                    "Loaded {} ecosystems; overlays cleared",
+                    ecos.len()
+                ));
+                set_dataset.set(DatasetState {
+                    replacement: Some(ecos),
+                    patches: Vec::new(),
+                });
+            }
+            Err(e) => set_message.set(e),
+        },
+    };
+
+    let remove_patch = move |index: usize| {
+        set_dataset.update(|d| {
+            d.patches.remove(index);
+        });
+        // Later patches may have depended on it.
+        let broken = dataset.with_untracked(|d| {
+            bundled.with_value(|b| apply_overlay(&mut d.base(b), &d.patches).err())
+        });
+        if let Some(e) = broken {
+            set_message.set(format!("Overlays no longer apply: {}", e));
+        }
+    };
+
+    let summary = move || {
+        dataset.with(|d| {
+            let base = match &d.replacement {
+                Some(ecos) => format!("Imported dataset ({} ecosystems)", ecos.len()),
+                None => format!("Bundled dataset {}", dataset_release()),
+            };
+            match d.patches.len() {
+                0 => base,
+                n => format!("{} + {} patch(es)", base, n),
+            }
+        })
+    };
+
+    let patch_list = move || {
+        dataset
+            .get()
+            .patches
+            .into_iter()
+            .enumerate()
+            .map(|(i, patch)| {
+                let line = patch.line();
+                view! {
+                    <li class="dataset-patch">
+                        <code>{line.clone()}</code>
+                        <button
+                            class="history-pin"
+                            aria-label=format!("Remove {}", line)
+                            on:click=move |_| remove_patch(i)
+                        >
+                            "×"
+                        </button>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let toml_href = move || ecosystems.with(|e| data_url("application/toml", &dataset_toml(e)));
+    let json_href = move || ecosystems.with(|e| data_url("application/json", &dataset_json(e)));
+
+    let mode_button = move |value: ImportMode, label: &'static str| {
+        view! {
+            <button
+                class=move || if mode.get() == value { "mode-btn active" } else { "mode-btn" }
+                on:click=move |_| set_mode.set(value)
+            >
+                {label}
+            </button>
+        }
+    };
+
+    view! {
+        <div class="weights-control">
+            <button
+                class=move || {
+                    if open.get() || dataset.with(DatasetState::is_modified) {
+                        "view-btn active"
+                    } else {
+                        "view-btn"
+                    }
+                }
+                on:click=move |_| set_open.update(|o| *o = !*o)
+            >
+                "DATA"
+            </button>
+            <Show when=move || open.get()>
+                <div class="weights-dropdown dataset-dropdown">
+                    <div class="dataset-summary">{summary}</div>
+
+                    <span class="mode-label">"Import a file as"</span>
+                    <div class="weights-profiles">
+                        {mode_button(ImportMode::Overlay, "Overlay")}
+                        {mode_button(ImportMode::Replace, "Replacement")}
+                    </div>
+                    <input
+                        class="dataset-file"
+                        type="file"
+                        accept=".toml,.json,.txt"
+                        on:change=move |ev| read_file(&ev, import)
+                    />
+
+                    <span class="mode-label">"Or type overlay patches"</span>
+                    <textarea
+                        class="pair-notes-text"
+                        rows="3"
+                        placeholder="set base.ecosystem_funding = 4"
+                        prop:value=move || typed.get()
+                        on:input=move |ev| set_typed.set(event_target_value(&ev))
+                    ></textarea>
+                    <button
+                        class="mode-btn"
+                        disabled=move || typed.with(|t| t.trim().is_empty())
+                        on:click=move |_| add_overlay(typed.get_untracked())
+                    >
+                        "Apply"
+                    </button>
+
+                    <div class="dataset-message" aria-live="polite">{move || message.get()}</div>
+
+                    <ul class="dataset-patches">{patch_list}</ul>
+
+                    <div class="weights-profiles">
+                        <a class="export-btn" href=toml_href download="ecosystems.toml">"Export TOML"</a>
+                        <a class="export-btn" href=json_href download="ecosystems.json">"Export JSON"</a>
+                        <button
+                            class="mode-btn"
+                            disabled=move || !dataset.with(DatasetState::is_modified)
+                            on:click=move |_| {
+                                let taken = custom
+                                    .with_untracked(|c| bundled.with_value(|b| taken_ids(c, b)));
+                                match taken {
+                                    None => {
+                                        set_dataset.set(DatasetState::default());
+                                        set_message.set("Back to the bundled dataset".to_string());
+                                    }
+                                    Some(taken) => set_message.set(format!(
+                                        "The bundled dataset uses ids of your own ecosystems: {}",
+                                        taken
+                                    )),
+                                }
+                            }
+                        >
+                            "Reset"
+                        </button>
+                    </div>
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -1,13 +1,19 @@
 use leptos::prelude::*;
 
-use crate::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::ecosystem::Ecosystem;
 
 #[component]
 pub fn EcosystemCard(
     ecosystem: Ecosystem,
     is_selected: Signal<bool>,
     #[prop(into)] on_click: Callback<String>,
-    #[prop(optional)] position_override: Option<(f64, f64)>,
+    #[prop(into)] position: Signal<[f64; 2]>,
+    /// Filtered out by the search or facet filters.
+    #[prop(into, optional)]
+    dimmed: Signal<bool>,
+    /// Some comparison involving this ecosystem has team notes.
+    #[prop(into, optional)]
+    has_notes: Signal<bool>,
 ) -> impl IntoView {
     let id = ecosystem.id.clone();
     let name = ecosystem.name.clone();
@@ -16,16 +22,27 @@ pub fn EcosystemCard(
     let evm_compat = ecosystem.evm_compatibility.clone();
     let deploy = ecosystem.deployment_options.join(" / ");
     let chain_layer = ecosystem.chain_layer.clone();
+    let aria_label = format!("{}: {}; VM {}; deploys as {}", name, languages, vm, deploy);
 
-    let (x, y) = position_override.unwrap_or((ecosystem.position[0], ecosystem.position[1]));
-    let style = format!("translate: {}px {}px;", x, y);
+    let source_title = |field: &str| match ecosystem.provenance(field) {
+        Some(p) => format!("Source: {} (verified {})", p.source, p.last_verified),
+        None => "No source recorded".to_string(),
+    };
+    let (languages_title, vm_title, deploy_title) = (
+        source_title("languages"),
+        source_title("vm"),
+        source_title("deployment_options"),
+    );
+
+    let style = move || {
+        let [x, y] = position.get();
+        format!("translate: {}px {}px;", x, y)
+    };
 
-    let class = move || {
-        if is_selected.get() {
-            "eco-card selected"
-        } else {
-            "eco-card"
-        }
+    let class = move || match (is_selected.get(), dimmed.get()) {
+        (true, _) => "eco-card selected",
+        (false, true) => "eco-card dimmed",
+        (false, false) => "eco-card",
     };
 
     let click_id = id.clone();
@@ -50,11 +67,19 @@ pub fn EcosystemCard(
             class=class
             style=style
             data-eco-id=id
+            data-nav=""
+            role="button"
+            tabindex="0"
+            aria-label=aria_label
+            aria-pressed=move || is_selected.get().to_string()
             on:click=on_card_click
         >
             <div class="eco-card-header">
                 <span class="eco-card-name">{name}</span>
                 <div class="eco-card-badges">
+                    <Show when=move || has_notes.get()>
+                        <span class="eco-card-badge notes-badge" title="Has notes">"✎"</span>
+                    </Show>
                     {l2_badge.map(|badge| view! {
                         <span class="eco-card-badge l2-badge">{badge}</span>
                     })}
@@ -63,9 +88,9 @@ pub fn EcosystemCard(
                     })}
                 </div>
             </div>
-            <div class="eco-card-detail">"Lang: "<span>{languages}</span></div>
-            <div class="eco-card-detail">"VM: "<span>{vm}</span></div>
-            <div class="eco-card-detail">"Deploy: "<span>{deploy}</span></div>
+            <div class="eco-card-detail" title=languages_title>"Lang: "<span>{languages}</span></div>
+            <div class="eco-card-detail" title=vm_title>"VM: "<span>{vm}</span></div>
+            <div class="eco-card-detail" title=deploy_title>"Deploy: "<span>{deploy}</span></div>
         </div>
     }
 }
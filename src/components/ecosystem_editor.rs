@@ -0,0 +1,568 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::custom::CUSTOM_GROUP;
+use crate::focus;
+use bc_techmap_core::data::ecosystem::{DeployMode, Ecosystem, Provenance};
+use bc_techmap_core::data::rules::EVM_COMPATIBILITY;
+use bc_techmap_core::data::tools::TOOL_CATEGORIES;
+
+/// Form state: lists and the position are kept as typed and parsed on save.
+#[derive(Clone, PartialEq)]
+struct Draft {
+    id: String,
+    name: String,
+    short: String,
+    languages: String,
+    vm: String,
+    transaction_model: String,
+    evm_compatibility: String,
+    deployment_options: String,
+    chain_layer: String,
+    l2_maturity: u8,
+    consensus: String,
+    tooling_maturity: u8,
+    tooling: String,
+    doc_quality: u8,
+    ecosystem_funding: u8,
+    group: String,
+    position: String,
+    /// Per-mode languages, by deploy mode.
+    mode_languages: HashMap<String, String>,
+    /// Deploy modes that aren't deployment options, such as an EVM layer;
+    /// kept from the ecosystem the draft started from.
+    extra_modes: Vec<String>,
+    /// Not edited here; kept from the custom ecosystem being edited and
+    /// cleared on clone.
+    provenance: BTreeMap<String, Provenance>,
+}
+
+fn split_list(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+impl Draft {
+    fn blank() -> Draft {
+        Draft {
+            id: String::new(),
+            name: String::new(),
+            short: String::new(),
+            languages: String::new(),
+            vm: String::new(),
+            transaction_model: "account".to_string(),
+            evm_compatibility: "none".to_string(),
+            deployment_options: "appchain".to_string(),
+            chain_layer: "L1".to_string(),
+            l2_maturity: 1,
+            consensus: String::new(),
+            tooling_maturity: 3,
+            tooling: String::new(),
+            doc_quality: 3,
+            ecosystem_funding: 3,
+            group: CUSTOM_GROUP.to_string(),
+            position: String::new(),
+            mode_languages: HashMap::new(),
+            extra_modes: Vec::new(),
+            provenance: BTreeMap::new(),
+        }
+    }
+
+    fn from_ecosystem(eco: &Ecosystem) -> Draft {
+        Draft {
+            id: eco.id.clone(),
+            name: eco.name.clone(),
+            short: eco.short.clone(),
+            languages: eco.languages.join(", "),
+            vm: eco.vm.clone(),
+            transaction_model: eco.transaction_model.clone(),
+            evm_compatibility: eco.evm_compatibility.clone(),
+            deployment_options: eco.deployment_options.join(", "),
+            chain_layer: eco.chain_layer.clone(),
+            l2_maturity: eco.l2_maturity,
+            consensus: eco.consensus.clone(),
+            tooling_maturity: eco.tooling_maturity,
+            tooling: eco.tooling.join(", "),
+            doc_quality: eco.doc_quality,
+            ecosystem_funding: eco.ecosystem_funding,
+            group: eco.group.clone(),
+            position: eco
+                .position
+                .map(|[x, y]| format!("{}, {}", x, y))
+                .unwrap_or_default(),
+            mode_languages: eco
+                .deploy_modes
+                .iter()
+                .flatten()
+                .map(|(mode, dm)| (mode.clone(), dm.languages.join(", ")))
+                .collect(),
+            extra_modes: eco
+                .mode_options()
+                .into_iter()
+                .filter(|mode| !eco.deployment_options.contains(mode))
+                .collect(),
+            provenance: eco.provenance.clone(),
+        }
+    }
+
+    /// The deployment options, then the extra deploy modes.
+    fn modes(&self) -> Vec<String> {
+        let mut modes = split_list(&self.deployment_options);
+        modes.extend(self.extra_modes.iter().cloned());
+        modes
+    }
+
+    /// The ecosystem this draft describes, or why the form can't be read.
+    fn to_ecosystem(&self) -> Result<Ecosystem, String> {
+        let position = match split_list(&self.position).as_slice() {
+            [] => None,
+            [x, y] => match (x.parse(), y.parse()) {
+                (Ok(x), Ok(y)) => Some([x, y]),
+                _ => return Err("position must be two numbers, \"x, y\"".to_string()),
+            },
+            _ => return Err("position must be two numbers, \"x, y\"".to_string()),
+        };
+
+        let deploy_modes: HashMap<String, DeployMode> = self
+            .modes()
+            .into_iter()
+            .filter_map(|mode| {
+                let languages = split_list(self.mode_languages.get(&mode)?);
+                (!languages.is_empty()).then_some((mode, DeployMode { languages }))
+            })
+            .collect();
+
+        Ok(Ecosystem {
+            id: self.id.trim().to_string(),
+            name: self.name.trim().to_string(),
+            short: self.short.trim().to_string(),
+            languages: split_list(&self.languages),
+            vm: self.vm.trim().to_string(),
+            transaction_model: self.transaction_model.trim().to_string(),
+            evm_compatibility: self.evm_compatibility.clone(),
+            deployment_options: split_list(&self.deployment_options),
+            chain_layer: self.chain_layer.trim().to_string(),
+            l2_maturity: self.l2_maturity,
+            consensus: self.consensus.trim().to_string(),
+            tooling_maturity: self.tooling_maturity,
+            tooling: split_list(&self.tooling),
+            doc_quality: self.doc_quality,
+            ecosystem_funding: self.ecosystem_funding,
+            group: self.group.trim().to_string(),
+            position,
+            deploy_modes: (!deploy_modes.is_empty()).then_some(deploy_modes),
+            provenance: self.provenance.clone(),
+        })
+    }
+}
+
+/// Values already used for a field, offered as suggestions.
+fn datalist(id: &'static str, values: Vec<String>) -> impl IntoView {
+    let mut values = values;
+    values.sort();
+    values.dedup();
+    view! {
+        <datalist id=id>
+            {values.into_iter().map(|v| view! { <option value=v></option> }).collect::<Vec<_>>()}
+        </datalist>
+    }
+}
+
+/// Create, clone, edit and delete custom ecosystems. They are checked with
+/// the same rules as the bundled data before they can be saved.
+#[component]
+pub fn EcosystemEditor(
+    ecosystems: Memo<Vec<Ecosystem>>,
+    custom: ReadSignal<Vec<Ecosystem>>,
+    set_custom: WriteSignal<Vec<Ecosystem>>,
+    set_open: WriteSignal<bool>,
+) -> impl IntoView {
+    let (draft, set_draft) = signal(Draft::blank());
+    // Id of the custom ecosystem being edited; `None` while creating one.
+    let (editing, set_editing) = signal(None::<String>);
+    let (status, set_status) = signal(String::new());
+
+    let parsed = Memo::new(move |_| draft.with(Draft::to_ecosystem));
+    let errors = Memo::new(move |_| match parsed.get() {
+        Err(e) => vec![e],
+        Ok(eco) => {
+            let original = editing.get();
+            ecosystems.with(|all| {
+                let others: Vec<Ecosystem> = all
+                    .iter()
+                    .filter(|e| Some(&e.id) != original.as_ref())
+                    .cloned()
+                    .collect();
+                eco.validate(&others)
+            })
+        }
+    });
+
+    let unknown_tools = Memo::new(move |_| {
+        parsed.with(|p| {
+            p.as_ref()
+                .map(|eco| eco.unknown_tools().join(", "))
+                .unwrap_or_default()
+        })
+    });
+
+    let start = move |d: Draft, id: Option<String>| {
+        set_draft.set(d);
+        set_editing.set(id);
+        set_status.set(String::new());
+    };
+
+    let save = move |_| {
+        let Ok(eco) = parsed.get_untracked() else {
+            return;
+        };
+        if !errors.with_untracked(Vec::is_empty) {
+            return;
+        }
+        let original = editing.get_untracked();
+        set_custom.update(|list| {
+            match original
+                .as_ref()
+                .and_then(|id| list.iter().position(|e| e.id == *id))
+            {
+                Some(i) => list[i] = eco.clone(),
+                None => list.push(eco.clone()),
+            }
+        });
+        set_status.set(format!("Saved {}", eco.name));
+        set_editing.set(Some(eco.id));
+    };
+
+    let delete = move |id: String| {
+        set_custom.update(|list| list.retain(|e| e.id != id));
+        if editing.get_untracked().as_ref() == Some(&id) {
+            start(Draft::blank(), None);
+        }
+    };
+
+    let clone_from = move |id: String| {
+        let Some(eco) = ecosystems.with_untracked(|all| all.iter().find(|e| e.id == id).cloned())
+        else {
+            return;
+        };
+        let mut d = Draft::from_ecosystem(&eco);
+        d.id = format!("{}-copy", eco.id);
+        d.name = format!("{} (copy)", eco.name);
+        d.position = String::new();
+        // The sources describe the original, not the clone.
+        d.provenance.clear();
+        start(d, None);
+    };
+
+    // Take focus, give it back to the card that had it when closing.
+    let dialog_ref = NodeRef::<leptos::html::Div>::new();
+    let return_to = focus::focused_eco_id();
+    Effect::new(move |_| {
+        if let Some(dialog) = dialog_ref.get() {
+            focus::focus_first(&dialog);
+        }
+    });
+    on_cleanup(move || {
+        if let Some(id) = return_to {
+            set_timeout(move || focus::restore_focus(&id), Duration::ZERO);
+        }
+    });
+
+    let text_field = move |label: &'static str,
+                           hint: &'static str,
+                           list: Option<&'static str>,
+                           get: fn(&Draft) -> &String,
+                           set: fn(&mut Draft, String)| {
+        view! {
+            <label class="editor-field">
+                <span class="effort-field-label">{label}</span>
+                <input
+                    class="effort-input"
+                    type="text"
+                    placeholder=hint
+                    list=list
+                    prop:value=move || draft.with(|d| get(d).clone())
+                    on:input=move |ev| {
+                        let value = event_target_value(&ev);
+                        set_draft.update(|d| set(d, value));
+                    }
+                />
+            </label>
+        }
+    };
+
+    let rating_field =
+        move |label: &'static str, get: fn(&Draft) -> u8, set: fn(&mut Draft, u8)| {
+            view! {
+                <label class="editor-field">
+                    <span class="effort-field-label">{label}</span>
+                    <input
+                        class="effort-input"
+                        type="number"
+                        min="1"
+                        max="5"
+                        prop:value=move || draft.with(|d| get(d).to_string())
+                        on:input=move |ev| {
+                            if let Ok(v) = event_target_value(&ev).parse::<u8>() {
+                                set_draft.update(|d| set(d, v));
+                            }
+                        }
+                    />
+                </label>
+            }
+        };
+
+    // One languages field per deploy mode, once there is more than one.
+    let mode_fields = move || {
+        let modes = draft.with(Draft::modes);
+        if modes.len() < 2 {
+            return None;
+        }
+        let fields = modes
+            .into_iter()
+            .map(|mode| {
+                let label = format!("Languages as {}", mode);
+                let key = mode.clone();
+                view! {
+                    <label class="editor-field">
+                        <span class="effort-field-label">{label}</span>
+                        <input
+                            class="effort-input"
+                            type="text"
+                            placeholder="Optional, e.g. Rust"
+                            prop:value=move || {
+                                draft.with(|d| d.mode_languages.get(&mode).cloned().unwrap_or_default())
+                            }
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                set_draft.update(|d| {
+                                    d.mode_languages.insert(key.clone(), value);
+                                });
+                            }
+                        />
+                    </label>
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(view! {
+            <div class="editor-section-title">"Deploy modes"</div>
+            <div class="editor-grid">{fields}</div>
+        })
+    };
+
+    let custom_list = move || {
+        custom
+            .get()
+            .into_iter()
+            .map(|eco| {
+                let edit_eco = eco.clone();
+                let delete_id = eco.id.clone();
+                let active_id = eco.id.clone();
+                view! {
+                    <li class=move || {
+                        if editing.get().as_ref() == Some(&active_id) {
+                            "editor-item active"
+                        } else {
+                            "editor-item"
+                        }
+                    }>
+                        <button
+                            class="history-restore"
+                            on:click=move |_| {
+                                start(Draft::from_ecosystem(&edit_eco), Some(edit_eco.id.clone()))
+                            }
+                        >
+                            <span class="history-route">{eco.name.clone()}</span>
+                            <span class="history-meta">{eco.id.clone()}</span>
+                        </button>
+                        <button
+                            class="history-pin"
+                            aria-label=format!("Delete {}", eco.name)
+                            on:click=move |_| delete(delete_id.clone())
+                        >
+                            "×"
+                        </button>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let clone_options = move || {
+        ecosystems
+            .get()
+            .into_iter()
+            .map(|e| view! { <option value=e.id.clone()>{e.name.clone()}</option> })
+            .collect::<Vec<_>>()
+    };
+
+    let suggestions = move |get: fn(&Ecosystem) -> String| {
+        ecosystems.with_untracked(|all| all.iter().map(get).collect::<Vec<_>>())
+    };
+    let known_tools: Vec<String> = TOOL_CATEGORIES
+        .iter()
+        .flat_map(|c| c.tools.iter().map(|t| t.to_string()))
+        .collect();
+
+    view! {
+        <div class="palette-backdrop" on:click=move |_| set_open.set(false)>
+            <div
+                class="ecosystem-editor"
+                node_ref=dialog_ref
+                role="dialog"
+                aria-modal="true"
+                aria-label="Custom ecosystems"
+                on:click=|ev| ev.stop_propagation()
+                on:keydown=move |ev: web_sys::KeyboardEvent| {
+                    if ev.key() == "Escape" {
+                        // Close the editor only, not the comparison underneath.
+                        ev.stop_propagation();
+                        set_open.set(false);
+                    }
+                }
+            >
+                <aside class="editor-sidebar">
+                    <div class="history-title">"Custom ecosystems"</div>
+                    <button class="mode-btn" on:click=move |_| start(Draft::blank(), None)>
+                        "New"
+                    </button>
+                    <select
+                        class="effort-input"
+                        aria-label="Clone an ecosystem"
+                        prop:value=""
+                        on:change=move |ev| {
+                            let id = event_target_value(&ev);
+                            if !id.is_empty() {
+                                clone_from(id);
+                            }
+                        }
+                    >
+                        <option value="">"Clone from…"</option>
+                        {clone_options}
+                    </select>
+                    <ul class="history-list">{custom_list}</ul>
+                    <Show when=move || custom.with(Vec::is_empty)>
+                        <p class="history-empty">"None yet. They are stored in this browser."</p>
+                    </Show>
+                </aside>
+
+                <div class="editor-form">
+                    <div class="editor-grid">
+                        {text_field("Id", "my-appchain", None, |d| &d.id, |d, v| d.id = v)}
+                        {text_field("Name", "My Appchain", None, |d| &d.name, |d, v| d.name = v)}
+                        {text_field("Short", "MYC", None, |d| &d.short, |d, v| d.short = v)}
+                        {text_field("Group", "custom", Some("editor-groups"), |d| &d.group, |d, v| d.group = v)}
+                        {text_field("Languages", "Rust, Go", None, |d| &d.languages, |d, v| d.languages = v)}
+                        {text_field("VM", "CosmWasm", Some("editor-vms"), |d| &d.vm, |d, v| d.vm = v)}
+                        {text_field(
+                            "Transaction model",
+                            "account",
+                            Some("editor-tx-models"),
+                            |d| &d.transaction_model,
+                            |d, v| d.transaction_model = v,
+                        )}
+                        <label class="editor-field">
+                            <span class="effort-field-label">"EVM compatibility"</span>
+                            <select
+                                class="effort-input"
+                                prop:value=move || draft.with(|d| d.evm_compatibility.clone())
+                                on:change=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    set_draft.update(|d| d.evm_compatibility = value);
+                                }
+                            >
+                                {EVM_COMPATIBILITY
+                                    .iter()
+                                    .map(|v| view! { <option value=*v>{*v}</option> })
+                                    .collect::<Vec<_>>()}
+                            </select>
+                        </label>
+                        {text_field(
+                            "Deployment options",
+                            "appchain, contract",
+                            None,
+                            |d| &d.deployment_options,
+                            |d, v| d.deployment_options = v,
+                        )}
+                        {text_field(
+                            "Chain layer",
+                            "L1",
+                            Some("editor-layers"),
+                            |d| &d.chain_layer,
+                            |d, v| d.chain_layer = v,
+                        )}
+                        {text_field("Consensus", "PoS (CometBFT)", None, |d| &d.consensus, |d, v| d.consensus = v)}
+                        {text_field("Position", "Optional, x, y", None, |d| &d.position, |d, v| d.position = v)}
+                    </div>
+
+                    <div class="editor-section-title">"Ratings (1–5)"</div>
+                    <div class="editor-grid">
+                        {rating_field("Tooling", |d| d.tooling_maturity, |d, v| d.tooling_maturity = v)}
+                        {rating_field("Docs", |d| d.doc_quality, |d, v| d.doc_quality = v)}
+                        {rating_field("Funding", |d| d.ecosystem_funding, |d, v| d.ecosystem_funding = v)}
+                        {rating_field("L2 maturity", |d| d.l2_maturity, |d, v| d.l2_maturity = v)}
+                    </div>
+
+                    <div class="editor-section-title">"Tooling"</div>
+                    <textarea
+                        class="pair-notes-text"
+                        rows="2"
+                        placeholder="Foundry, CosmJS — names from tool_categories.toml"
+                        prop:value=move || draft.with(|d| d.tooling.clone())
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev);
+                            set_draft.update(|d| d.tooling = value);
+                        }
+                    ></textarea>
+
+                    {mode_fields}
+
+                    {datalist("editor-groups", suggestions(|e| e.group.clone()))}
+                    {datalist("editor-vms", suggestions(|e| e.vm.clone()))}
+                    {datalist("editor-tx-models", suggestions(|e| e.transaction_model.clone()))}
+                    {datalist("editor-layers", suggestions(|e| e.chain_layer.clone()))}
+                    <details class="editor-tools">
+                        <summary>"Known tools"</summary>
+                        <p>{known_tools.join(", ")}</p>
+                    </details>
+
+                    <Show when=move || unknown_tools.with(|t| !t.is_empty())>
+                        <p class="editor-warning">
+                            "Not in the known tools, so left out of plan tool swaps: "
+                            {move || unknown_tools.get()}
+                        </p>
+                    </Show>
+
+                    <ul class="editor-errors" aria-live="polite">
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|e| view! { <li>{e}</li> })
+                                .collect::<Vec<_>>()
+                        }}
+                    </ul>
+
+                    <div class="editor-actions">
+                        <span class="editor-status" aria-live="polite">{move || status.get()}</span>
+                        <button class="mode-btn" on:click=move |_| set_open.set(false)>
+                            "Close"
+                        </button>
+                        <button
+                            class="mode-btn active"
+                            disabled=move || !errors.with(Vec::is_empty)
+                            on:click=save
+                        >
+                            {move || if editing.get().is_some() { "Save changes" } else { "Add ecosystem" }}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+
+use bc_techmap_core::data::effort::{estimate_effort, ProjectProfile};
+use bc_techmap_core::data::scoring::MigrationReport;
+
+fn weeks(v: f64) -> String {
+    format!("{:.0}", v.ceil())
+}
+
+#[component]
+pub fn EffortEstimator(
+    report: Signal<MigrationReport>,
+    project: ReadSignal<ProjectProfile>,
+    set_project: WriteSignal<ProjectProfile>,
+) -> impl IntoView {
+    let estimate = Signal::derive(move || estimate_effort(&report.get(), &project.get()));
+
+    let field = move |label: &'static str,
+                      get: fn(&ProjectProfile) -> u32,
+                      set: fn(&mut ProjectProfile, u32)| {
+        view! {
+            <label class="effort-field">
+                <span class="effort-field-label">{label}</span>
+                <input
+                    class="effort-input"
+                    type="number"
+                    min="0"
+                    prop:value=move || get(&project.get()).to_string()
+                    on:input=move |ev| {
+                        if let Ok(v) = event_target_value(&ev).parse::<u32>() {
+                            set_project.update(|p| set(p, v));
+                        }
+                    }
+                />
+            </label>
+        }
+    };
+
+    view! {
+        <div class="effort">
+            <div class="effort-title">"Effort Estimate"</div>
+            <div class="effort-inputs">
+                {field("Contracts", |p| p.contracts, |p, v| p.contracts = v)}
+                {field("LOC", |p| p.loc, |p, v| p.loc = v)}
+                {field("Team", |p| p.team_size, |p, v| p.team_size = v.max(1))}
+                {field(
+                    "Coverage %",
+                    |p| (p.test_coverage * 100.0).round() as u32,
+                    |p, v| p.test_coverage = (v.min(100) as f64) / 100.0,
+                )}
+            </div>
+
+            {move || {
+                let e = estimate.get();
+                let (cal_low, cal_high) = e.calendar_weeks(project.get().team_size);
+                view! {
+                    <div class="effort-phases">
+                        {e.phases.iter().map(|p| view! {
+                            <div class="effort-phase">
+                                <span class="effort-phase-name">{p.phase.name()}</span>
+                                <span class="effort-phase-value">
+                                    {format!("{}–{} pw", weeks(p.low), weeks(p.high))}
+                                </span>
+                            </div>
+                        }).collect::<Vec<_>>()}
+                    </div>
+                    <div class="effort-total">
+                        {format!("{}–{} person-weeks", weeks(e.low), weeks(e.high))}
+                    </div>
+                    <div class="effort-calendar">
+                        {format!("≈ {}–{} weeks for the team", weeks(cal_low), weeks(cal_high))}
+                    </div>
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::effort::ProjectProfile;
+use bc_techmap_core::data::scoring::Weights;
+use bc_techmap_core::export::{data_url, report_json, report_markdown, ReportExport, ReportInputs};
+use bc_techmap_core::render::{report_html, report_svg};
+
+fn copy_to_clipboard(text: &str) {
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().clipboard().write_text(text);
+    }
+}
+
+#[component]
+pub fn ExportMenu(
+    /// The bundled dataset, to note edited ecosystems in exports.
+    bundled: StoredValue<Vec<Ecosystem>>,
+    /// The ecosystems as scored, what-if edits included.
+    #[prop(into)]
+    source: Signal<Ecosystem>,
+    #[prop(into)] dest: Signal<Ecosystem>,
+    src_mode: ReadSignal<Option<String>>,
+    dst_mode: ReadSignal<Option<String>>,
+    project: ReadSignal<ProjectProfile>,
+    weights: ReadSignal<Weights>,
+    /// Notes for the current route; left out of exports when blank.
+    #[prop(into)]
+    notes: Signal<String>,
+) -> impl IntoView {
+    let (source_id, dest_id) = (
+        source.with_untracked(|s| s.id.clone()),
+        dest.with_untracked(|d| d.id.clone()),
+    );
+    let base_name = format!("migration-{}-{}", source_id, dest_id);
+    let md_name = format!("{}.md", base_name);
+    let json_name = format!("{}.json", base_name);
+    let svg_name = format!("report-{}-{}.svg", source_id, dest_id);
+    let html_name = format!("report-{}-{}.html", source_id, dest_id);
+
+    let export = Signal::derive(move || {
+        let inputs = ReportInputs {
+            source: source.get(),
+            dest: dest.get(),
+            src_mode: src_mode.get(),
+            dst_mode: dst_mode.get(),
+            project: project.get(),
+            weights: weights.get(),
+            notes: Some(notes.get()).filter(|n| !n.trim().is_empty()),
+        };
+        bundled.with_value(|b| ReportExport::new(inputs, b))
+    });
+
+    let md_href = move || data_url("text/markdown", &report_markdown(&export.get()));
+    let json_href = move || data_url("application/json", &report_json(&export.get()));
+    let svg_href = move || data_url("image/svg+xml", &report_svg(&export.get()));
+    let html_href = move || data_url("text/html", &report_html(&export.get()));
+
+    view! {
+        <div class="export-menu">
+            <span class="export-label">"Export"</span>
+            <a class="export-btn" href=md_href download=md_name>"MD"</a>
+            <a class="export-btn" href=json_href download=json_name>"JSON"</a>
+            <a class="export-btn" href=svg_href download=svg_name>"SVG"</a>
+            <a class="export-btn" href=html_href download=html_name>"HTML"</a>
+            <button
+                class="export-btn"
+                on:click=move |_| copy_to_clipboard(&report_markdown(&export.get_untracked()))
+            >
+                "Copy MD"
+            </button>
+            <button
+                class="export-btn"
+                on:click=move |_| copy_to_clipboard(&report_json(&export.get_untracked()))
+            >
+                "Copy JSON"
+            </button>
+            <button
+                class="export-btn"
+                on:click=move |_| {
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.print();
+                    }
+                }
+            >
+                "Print"
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1,140 @@
+use leptos::prelude::*;
+
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::filter::{Facet, Filter, MinScore};
+
+/// Header search box plus a dropdown of facet filters.
+#[component]
+pub fn FilterBar(
+    ecosystems: Vec<Ecosystem>,
+    filter: ReadSignal<Filter>,
+    set_filter: WriteSignal<Filter>,
+) -> impl IntoView {
+    let (open, set_open) = signal(false);
+    let total = ecosystems.len();
+    let ecosystems = StoredValue::new(ecosystems);
+
+    let match_count = move || {
+        filter.with(|f| ecosystems.with_value(|ecos| ecos.iter().filter(|e| f.matches(e)).count()))
+    };
+
+    let button_label = move || {
+        let active = filter.with(|f| {
+            f.facets.iter().map(Vec::len).sum::<usize>()
+                + f.min_scores.iter().filter(|&&m| m > 0).count()
+        });
+        if active > 0 {
+            format!("FILTERS ({})", active)
+        } else {
+            "FILTERS".to_string()
+        }
+    };
+
+    let facet_sections = move || {
+        Facet::ALL
+            .iter()
+            .map(|&facet| {
+                let options = ecosystems.with_value(|ecos| facet.options(ecos));
+                view! {
+                    <div class="filter-section">
+                        <span class="mode-label">{facet.name()}</span>
+                        <div class="filter-options">
+                            {options.into_iter().map(|value| {
+                                let class_value = value.clone();
+                                let label = value.clone();
+                                view! {
+                                    <button
+                                        class=move || {
+                                            if filter.with(|f| f.is_selected(facet, &class_value)) {
+                                                "mode-btn active"
+                                            } else {
+                                                "mode-btn"
+                                            }
+                                        }
+                                        on:click=move |_| set_filter.update(|f| f.toggle(facet, &value))
+                                    >
+                                        {label}
+                                    </button>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let score_fields = move || {
+        MinScore::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, score)| {
+                view! {
+                    <label class="weights-field">
+                        <span class="weights-field-label">{format!("Min {}", score.name().to_lowercase())}</span>
+                        <input
+                            class="weights-slider"
+                            type="range"
+                            min="0"
+                            max="5"
+                            prop:value=move || filter.with(|f| f.min_scores[i].to_string())
+                            on:input=move |ev| {
+                                if let Ok(v) = event_target_value(&ev).parse::<u8>() {
+                                    set_filter.update(|f| f.min_scores[i] = v);
+                                }
+                            }
+                        />
+                        <span class="weights-field-value">
+                            {move || match filter.with(|f| f.min_scores[i]) {
+                                0 => "any".to_string(),
+                                n => format!("{}+", n),
+                            }}
+                        </span>
+                    </label>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="filter-bar">
+            <input
+                class="search-input"
+                type="search"
+                placeholder="Search…"
+                prop:value=move || filter.with(|f| f.query.clone())
+                on:input=move |ev| {
+                    let query = event_target_value(&ev);
+                    set_filter.update(|f| f.query = query);
+                }
+            />
+            <button
+                class=move || {
+                    if open.get() || filter.with(Filter::is_active) {
+                        "view-btn active"
+                    } else {
+                        "view-btn"
+                    }
+                }
+                on:click=move |_| set_open.update(|o| *o = !*o)
+            >
+                {button_label}
+            </button>
+            <Show when=move || filter.with(Filter::is_active)>
+                <span class="filter-count">{move || format!("{}/{}", match_count(), total)}</span>
+            </Show>
+            <Show when=move || open.get()>
+                <div class="filter-dropdown">
+                    {facet_sections}
+                    <div class="filter-section">{score_fields}</div>
+                    <button
+                        class="mode-btn"
+                        on:click=move |_| set_filter.set(Filter::default())
+                    >
+                        "Clear filters"
+                    </button>
+                </div>
+            </Show>
+        </div>
+    }
+}
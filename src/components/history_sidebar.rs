@@ -0,0 +1,122 @@
+use leptos::prelude::*;
+
+use crate::history::{History, HistoryEntry};
+use bc_techmap_core::data::ecosystem::Ecosystem;
+
+fn short_name(ecosystems: &[Ecosystem], id: &str) -> String {
+    ecosystems
+        .iter()
+        .find(|e| e.id == id)
+        .map_or_else(|| id.to_uppercase(), |e| e.short.clone())
+}
+
+fn entry_modes(entry: &HistoryEntry) -> Option<String> {
+    match (&entry.src_mode, &entry.dst_mode) {
+        (None, None) => None,
+        (s, d) => Some(format!(
+            "{} → {}",
+            s.as_deref().unwrap_or("—"),
+            d.as_deref().unwrap_or("—")
+        )),
+    }
+}
+
+/// Recently viewed comparisons, newest first, with pins and back/forward.
+#[component]
+pub fn HistorySidebar(
+    ecosystems: Vec<Ecosystem>,
+    history: ReadSignal<History>,
+    set_history: WriteSignal<History>,
+    #[prop(into)] on_restore: Callback<usize>,
+) -> impl IntoView {
+    let ecosystems = StoredValue::new(ecosystems);
+
+    let entries = move || {
+        let (entries, cursor) = history.with(|h| (h.entries.clone(), h.cursor));
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let route = ecosystems.with_value(|ecos| {
+                    format!(
+                        "{} → {}",
+                        short_name(ecos, &entry.source),
+                        short_name(ecos, &entry.dest)
+                    )
+                });
+                let modes = entry_modes(&entry);
+                let meta = format!(
+                    "{:.1}/5 · {} · {} weights",
+                    entry.overall * 5.0,
+                    entry.difficulty_label,
+                    entry.weights.profile_name().unwrap_or("custom")
+                );
+                let class = if i == cursor {
+                    "history-entry active"
+                } else {
+                    "history-entry"
+                };
+                let pinned = entry.pinned;
+                view! {
+                    <li class=class>
+                        <button class="history-restore" on:click=move |_| on_restore.run(i)>
+                            <span class="history-route">{route}</span>
+                            {modes.map(|m| view! { <span class="history-modes">{m}</span> })}
+                            <span class="history-meta">{meta}</span>
+                        </button>
+                        <button
+                            class=if pinned { "history-pin pinned" } else { "history-pin" }
+                            aria-label=if pinned { "Unpin comparison" } else { "Pin comparison" }
+                            aria-pressed=pinned.to_string()
+                            on:click=move |_| set_history.update(|h| h.toggle_pin(i))
+                        >
+                            {if pinned { "★" } else { "☆" }}
+                        </button>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <aside class="history-sidebar" aria-label="Comparison history">
+            <div class="history-header">
+                <span class="history-title">"History"</span>
+                <div class="mode-buttons">
+                    <button
+                        class="mode-btn"
+                        aria-label="Back"
+                        disabled=move || !history.with(History::can_go_back)
+                        on:click=move |_| on_restore.run(history.with_untracked(|h| h.cursor + 1))
+                    >
+                        "‹"
+                    </button>
+                    <button
+                        class="mode-btn"
+                        aria-label="Forward"
+                        disabled=move || !history.with(History::can_go_forward)
+                        on:click=move |_| {
+                            on_restore.run(history.with_untracked(|h| h.cursor.saturating_sub(1)))
+                        }
+                    >
+                        "›"
+                    </button>
+                </div>
+            </div>
+            <Show
+                when=move || history.with(|h| !h.entries.is_empty())
+                fallback=|| view! {
+                    <p class="history-empty">"Comparisons you open show up here."</p>
+                }
+            >
+                <ul class="history-list">{entries}</ul>
+                <button
+                    class="mode-btn history-clear"
+                    on:click=move |_| set_history.update(History::clear_recent)
+                >
+                    "Clear unpinned"
+                </button>
+            </Show>
+        </aside>
+    }
+}
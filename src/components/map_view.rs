@@ -0,0 +1,251 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+
+use crate::app::AppState;
+use crate::components::ring_view::ring_difficulty_class;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::scoring::{
+    compute_migration_weighted, difficulty_band, Weights, DIFFICULTY_LABELS,
+};
+use bc_techmap_core::filter::Filter;
+use bc_techmap_core::layout::{classical_mds, distance_matrix, normalize, remove_overlaps};
+
+const MAP_NODE_W: f64 = 110.0;
+const MAP_NODE_H: f64 = 36.0;
+/// Space kept clear around the layout, including room for the controls.
+const MAP_MARGIN: f64 = 72.0;
+/// Edge thresholds offered, as indices into `DIFFICULTY_LABELS`.
+const EDGE_BANDS: [usize; 3] = [0, 1, 2];
+
+/// Ecosystems laid out by symmetric migration difficulty (classical MDS),
+/// so similar ecosystems cluster. Recomputes when the weights change.
+#[component]
+pub fn MapView(
+    ecosystems: Vec<Ecosystem>,
+    state: ReadSignal<AppState>,
+    weights: ReadSignal<Weights>,
+    filter: ReadSignal<Filter>,
+    #[prop(into)] on_card_click: Callback<String>,
+    on_canvas_click: impl Fn() + 'static + Copy,
+) -> impl IntoView {
+    let (viewport_w, set_viewport_w) = signal(0.0_f64);
+    let (viewport_h, set_viewport_h) = signal(0.0_f64);
+
+    let update_viewport = move || {
+        let w = web_sys::window().unwrap();
+        let width = w.inner_width().unwrap().as_f64().unwrap_or(1200.0);
+        let height = w.inner_height().unwrap().as_f64().unwrap_or(800.0);
+        set_viewport_w.set(width);
+        set_viewport_h.set(height - 48.0);
+    };
+
+    update_viewport();
+
+    let resize_closure = Closure::<dyn Fn()>::new(move || {
+        update_viewport();
+    });
+    let _ = web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback("resize", resize_closure.as_ref().unchecked_ref());
+    resize_closure.forget();
+
+    let ecosystems = StoredValue::new(ecosystems);
+    let (edge_band, set_edge_band) = signal(Some(1_usize));
+
+    let distances =
+        Memo::new(move |_| ecosystems.with_value(|ecos| distance_matrix(ecos, &weights.get())));
+
+    // Pixel positions of node centers.
+    let positions = Memo::new(move |_| {
+        let (w, h) = (viewport_w.get(), viewport_h.get());
+        let mut points: Vec<[f64; 2]> = normalize(&classical_mds(&distances.get()))
+            .into_iter()
+            .map(|[x, y]| {
+                [
+                    MAP_MARGIN + x * (w - 2.0 * MAP_MARGIN).max(0.0),
+                    MAP_MARGIN + y * (h - 2.0 * MAP_MARGIN).max(0.0),
+                ]
+            })
+            .collect();
+        remove_overlaps(&mut points, MAP_NODE_W + 8.0, MAP_NODE_H + 8.0, 60);
+        points
+    });
+
+    let source_id = move || match state.get() {
+        AppState::Idle => None,
+        AppState::SourceSelected { source_id } | AppState::ShowResults { source_id, .. } => {
+            Some(source_id)
+        }
+    };
+
+    let handle_click = move |ev: web_sys::MouseEvent| {
+        let target = ev.target().unwrap();
+        let element: &web_sys::Element = target.dyn_ref().unwrap();
+        if element.closest(".map-node").unwrap().is_none()
+            && element.closest(".map-controls").unwrap().is_none()
+        {
+            on_canvas_click();
+        }
+    };
+
+    let edges = move || {
+        let Some(max_band) = edge_band.get() else {
+            return Vec::new();
+        };
+        let d = distances.get();
+        let p = positions.get();
+        let source = source_id();
+        ecosystems.with_value(|ecos| {
+            let mut lines = Vec::new();
+            for i in 0..ecos.len() {
+                for j in (i + 1)..ecos.len() {
+                    let band = difficulty_band(d[i][j]);
+                    let both_match = filter.with(|f| f.matches(&ecos[i]) && f.matches(&ecos[j]));
+                    if band > max_band || !both_match {
+                        continue;
+                    }
+                    let touches_source = source
+                        .as_deref()
+                        .is_some_and(|s| ecos[i].id == s || ecos[j].id == s);
+                    let class = format!(
+                        "map-edge {}{}",
+                        ring_difficulty_class(band),
+                        if touches_source { " highlighted" } else { "" }
+                    );
+                    lines.push(view! {
+                        <line
+                            class=class
+                            x1=p[i][0]
+                            y1=p[i][1]
+                            x2=p[j][0]
+                            y2=p[j][1]
+                        />
+                    });
+                }
+            }
+            lines
+        })
+    };
+
+    let nodes = ecosystems.with_value(|ecos| {
+        ecos.iter()
+            .enumerate()
+            .map(|(i, eco)| {
+                let eco_id = eco.id.clone();
+                let name = eco.name.clone();
+                let style = move || {
+                    let [x, y] = positions.get()[i];
+                    format!(
+                        "translate: {:.1}px {:.1}px;",
+                        x - MAP_NODE_W / 2.0,
+                        y - MAP_NODE_H / 2.0
+                    )
+                };
+                let class_id = eco_id.clone();
+                let eco_for_filter = eco.clone();
+                let class = move || {
+                    let dimmed = if filter.with(|f| f.matches(&eco_for_filter)) {
+                        ""
+                    } else {
+                        " dimmed"
+                    };
+                    let (src, dst) = match state.get() {
+                        AppState::Idle => (None, None),
+                        AppState::SourceSelected { source_id } => (Some(source_id), None),
+                        AppState::ShowResults { source_id, dest_id } => {
+                            (Some(source_id), Some(dest_id))
+                        }
+                    };
+                    if src.as_deref() == Some(&class_id) {
+                        return format!("map-node source{}", dimmed);
+                    }
+                    let active = if dst.as_deref() == Some(&class_id) {
+                        " active"
+                    } else {
+                        ""
+                    };
+                    // Tint by difficulty from the selected source.
+                    let tint = src
+                        .and_then(|s| {
+                            ecosystems.with_value(|ecos| {
+                                let src = ecos.iter().find(|e| e.id == s)?;
+                                let dst = ecos.iter().find(|e| e.id == class_id)?;
+                                let r = compute_migration_weighted(
+                                    src,
+                                    dst,
+                                    None,
+                                    None,
+                                    &weights.get(),
+                                );
+                                Some(ring_difficulty_class(difficulty_band(r.overall)))
+                            })
+                        })
+                        .unwrap_or("");
+                    format!("map-node{} {}{}", active, tint, dimmed)
+                };
+                let click_id = eco_id.clone();
+                let aria_name = name.clone();
+                let aria_pressed_id = eco_id.clone();
+                view! {
+                    <div
+                        class=class
+                        style=style
+                        data-eco-id=eco_id
+                        data-nav=""
+                        role="button"
+                        tabindex="0"
+                        aria-label=aria_name
+                        aria-pressed=move || {
+                            match state.get() {
+                                AppState::Idle => false,
+                                AppState::SourceSelected { source_id } => source_id == aria_pressed_id,
+                                AppState::ShowResults { source_id, dest_id } => {
+                                    source_id == aria_pressed_id || dest_id == aria_pressed_id
+                                }
+                            }
+                            .to_string()
+                        }
+                        on:click=move |ev: web_sys::MouseEvent| {
+                            ev.stop_propagation();
+                            on_card_click.run(click_id.clone());
+                        }
+                    >
+                        <span class="map-node-name">{name}</span>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    view! {
+        <div class="map-canvas" on:click=handle_click>
+            <div class="map-controls">
+                <span class="mode-label">"Edges"</span>
+                <div class="mode-buttons">
+                    <button
+                        class=move || if edge_band.get().is_none() { "mode-btn active" } else { "mode-btn" }
+                        on:click=move |_| set_edge_band.set(None)
+                    >
+                        "None"
+                    </button>
+                    {EDGE_BANDS.iter().map(|&band| view! {
+                        <button
+                            class=move || {
+                                if edge_band.get() == Some(band) { "mode-btn active" } else { "mode-btn" }
+                            }
+                            title=format!("Pairs rated {} or easier", DIFFICULTY_LABELS[band])
+                            on:click=move |_| set_edge_band.set(Some(band))
+                        >
+                            {format!("≤ {}", DIFFICULTY_LABELS[band])}
+                        </button>
+                    }).collect::<Vec<_>>()}
+                </div>
+            </div>
+            <svg class="map-svg" xmlns="http://www.w3.org/2000/svg">
+                {edges}
+            </svg>
+            {nodes}
+        </div>
+    }
+}
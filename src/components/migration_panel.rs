@@ -1,66 +1,170 @@
+use std::time::Duration;
+
 use leptos::prelude::*;
 
-use crate::data::ecosystem::Ecosystem;
-use crate::data::scoring::compute_migration;
+use crate::components::effort_estimator::EffortEstimator;
+use crate::components::export_menu::ExportMenu;
+use crate::components::migration_plan::MigrationPlanView;
+use crate::components::pair_notes::PairNotes;
+use crate::components::what_if::WhatIf;
+use crate::focus;
+use crate::history::HistoryEntry;
+use crate::notes::Notes;
+use bc_techmap_core::api::selectable_modes;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::effort::ProjectProfile;
+use bc_techmap_core::data::provenance::provenance_text;
+use bc_techmap_core::data::scoring::{compute_migration_weighted, Weights};
+use bc_techmap_core::text::{
+    finding_text, mitigation_category_label, severity_class, severity_label,
+};
 
 #[component]
 pub fn MigrationPanel(
     source: Ecosystem,
     dest: Ecosystem,
+    weights: ReadSignal<Weights>,
+    bundled: StoredValue<Vec<Ecosystem>>,
+    /// Everything on the map, for the what-if choices.
+    ecosystems: Memo<Vec<Ecosystem>>,
     on_close: impl Fn() + 'static + Copy,
+    /// Deploy modes to open with, e.g. when restoring from history; ignored
+    /// if the ecosystem doesn't offer them.
+    #[prop(optional_no_strip)]
+    initial_src_mode: Option<String>,
+    #[prop(optional_no_strip)] initial_dst_mode: Option<String>,
+    /// Called with the comparison on screen whenever it changes.
+    #[prop(into)]
+    on_view: Callback<HistoryEntry>,
+    notes: ReadSignal<Notes>,
+    set_notes: WriteSignal<Notes>,
 ) -> impl IntoView {
     let source_name = source.name.clone();
     let dest_name = dest.name.clone();
     let source_short = source.short.clone();
     let dest_short = dest.short.clone();
 
-    let src_has_modes = source.deploy_modes.is_some() && source.deployment_options.len() > 1;
-    let dst_has_modes = dest.deploy_modes.is_some() && dest.deployment_options.len() > 1;
+    let src_mode_options = selectable_modes(&source);
+    let dst_mode_options = selectable_modes(&dest);
+    let src_has_modes = !src_mode_options.is_empty();
+    let dst_has_modes = !dst_mode_options.is_empty();
 
-    let src_mode_options: Vec<String> = if src_has_modes {
-        source.deployment_options.clone()
+    let (src_mode, set_src_mode) = signal(if src_has_modes {
+        initial_src_mode
+            .filter(|m| src_mode_options.contains(m))
+            .or_else(|| src_mode_options.first().cloned())
     } else {
-        vec![]
-    };
-    let dst_mode_options: Vec<String> = if dst_has_modes {
-        dest.deployment_options.clone()
+        None
+    });
+    let (dst_mode, set_dst_mode) = signal(if dst_has_modes {
+        initial_dst_mode
+            .filter(|m| dst_mode_options.contains(m))
+            .or_else(|| dst_mode_options.first().cloned())
     } else {
-        vec![]
-    };
-
-    let (src_mode, set_src_mode) = signal(
-        if src_has_modes {
-            src_mode_options.first().cloned()
-        } else {
-            None
-        },
-    );
-    let (dst_mode, set_dst_mode) = signal(
-        if dst_has_modes {
-            dst_mode_options.first().cloned()
-        } else {
-            None
-        },
-    );
-
-    let src_for_report = source.clone();
-    let dst_for_report = dest.clone();
+        None
+    });
+
+    let (project, set_project) = signal(ProjectProfile::default());
+
+    // What-if edits; the report and exports score these.
+    let (src_edit, set_src_edit) = signal(source.clone());
+    let (dst_edit, set_dst_edit) = signal(dest.clone());
 
     let report = Signal::derive(move || {
-        compute_migration(
-            &src_for_report,
-            &dst_for_report,
+        src_edit.with(|s| {
+            dst_edit.with(|d| {
+                compute_migration_weighted(
+                    s,
+                    d,
+                    src_mode.get().as_deref(),
+                    dst_mode.get().as_deref(),
+                    &weights.get(),
+                )
+            })
+        })
+    });
+
+    let src_for_baseline = source.clone();
+    let dst_for_baseline = dest.clone();
+    let baseline = Signal::derive(move || {
+        compute_migration_weighted(
+            &src_for_baseline,
+            &dst_for_baseline,
             src_mode.get().as_deref(),
             dst_mode.get().as_deref(),
+            &weights.get(),
         )
     });
 
+    let (notes_src, notes_dst) = (source.id.clone(), dest.id.clone());
+    let route_notes = Signal::derive(move || {
+        let (s, d) = (src_mode.get(), dst_mode.get());
+        notes.with(|n| {
+            n.get(&notes_src, &notes_dst, s.as_deref(), d.as_deref())
+                .to_string()
+        })
+    });
+
+    let (source_id, dest_id) = (source.id.clone(), dest.id.clone());
+    Effect::new(move |_| {
+        let r = baseline.get();
+        on_view.run(HistoryEntry {
+            source: source_id.clone(),
+            dest: dest_id.clone(),
+            src_mode: src_mode.get(),
+            dst_mode: dst_mode.get(),
+            weights: weights.get(),
+            overall: r.overall,
+            difficulty_label: r.difficulty_label,
+            pinned: false,
+        });
+    });
+
+    // Move focus into the panel, and back to the card that opened it on close.
+    let panel_ref = NodeRef::<leptos::html::Div>::new();
+    let return_to = focus::focused_eco_id();
+    Effect::new(move |_| {
+        if let Some(panel) = panel_ref.get() {
+            focus::focus_first(&panel);
+        }
+    });
+    on_cleanup(move || {
+        if let Some(id) = return_to {
+            set_timeout(move || focus::restore_focus(&id), Duration::ZERO);
+        }
+    });
+
+    let dialog_label = format!("Migration analysis: {} to {}", source.name, dest.name);
+
     view! {
-        <div class="migration-panel">
-            <button class="panel-close" on:click=move |_| on_close()>"[ESC]"</button>
+        <div
+            class="migration-panel"
+            node_ref=panel_ref
+            role="dialog"
+            aria-modal="true"
+            aria-label=dialog_label
+        >
+            <button
+                class="panel-close"
+                aria-label="Close migration analysis"
+                on:click=move |_| on_close()
+            >
+                "[ESC]"
+            </button>
 
             <div class="panel-header">"Migration Analysis"</div>
 
+            <ExportMenu
+                bundled=bundled
+                source=src_edit
+                dest=dst_edit
+                src_mode=src_mode
+                dst_mode=dst_mode
+                project=project
+                weights=weights
+                notes=route_notes
+            />
+
             <div class="panel-route">
                 {source_name}
                 <span class="panel-route-arrow">" → "</span>
@@ -129,16 +233,22 @@ pub fn MigrationPanel(
 
             {move || {
                 let r = report.get();
+                let (src_now, dst_now) = (src_edit.get(), dst_edit.get());
                 let overall_pct = (r.overall * 100.0) as u32;
                 let score_display = format!("{:.1}/5", r.overall * 5.0);
                 let difficulty = r.difficulty_label.clone();
                 let has_positives = !r.positives.is_empty();
+                let pair_override = r.pair_override;
 
                 view! {
                     <div>
                         <div class="difficulty-score">
                             <div class="difficulty-label">"Overall Difficulty"</div>
-                            <div class="difficulty-bar">
+                            <div
+                                class="difficulty-bar"
+                                role="img"
+                                aria-label=format!("Overall difficulty {}, {}", score_display, difficulty)
+                            >
                                 <div
                                     class="difficulty-fill"
                                     style=format!("width: {}%", overall_pct)
@@ -153,13 +263,29 @@ pub fn MigrationPanel(
                                 let pct = (dim.score * 100.0) as u32;
                                 let name = dim.name.clone();
                                 let label = dim.label.clone();
+                                let aria_label = format!("{}: {} ({}%)", dim.name, dim.label, pct);
+                                let sources = provenance_text(&src_now, &dst_now, dim.dimension);
+                                let item_class = if dim.overridden {
+                                    "dimension-item overridden"
+                                } else {
+                                    "dimension-item"
+                                };
                                 view! {
-                                    <div class="dimension-item">
+                                    <div class=item_class>
                                         <div class="dimension-header">
-                                            <span class="dimension-name">{name}</span>
+                                            <span class="dimension-name" title=sources>
+                                                {name}
+                                                {dim.overridden.then(|| view! {
+                                                    <span class="dimension-override-mark" title="Adjusted by a curated pair override">"*"</span>
+                                                })}
+                                            </span>
                                             <span class="dimension-score">{label}</span>
                                         </div>
-                                        <div class="dimension-bar">
+                                        <div
+                                            class="dimension-bar"
+                                            role="img"
+                                            aria-label=aria_label
+                                        >
                                             <div
                                                 class="dimension-fill"
                                                 style=format!("width: {}%", pct)
@@ -170,12 +296,52 @@ pub fn MigrationPanel(
                             }).collect::<Vec<_>>()}
                         </div>
 
+                        {pair_override.map(|ov| view! {
+                            <div class="override-note">
+                                <span class="override-note-title">"* Curated override"</span>
+                                <p class="override-note-text">{ov.justification}</p>
+                                <a
+                                    class="override-note-link"
+                                    href=ov.source_url
+                                    target="_blank"
+                                    rel="noopener noreferrer"
+                                >
+                                    "Source"
+                                </a>
+                            </div>
+                        })}
+
                         <div class="challenges">
                             <div class="challenges-title">"Key Challenges"</div>
                             {r.challenges.iter().map(|c| {
-                                let text = c.clone();
+                                let text = finding_text(c);
+                                let class = format!("challenge-item {}", severity_class(c.severity));
+                                let mitigations = c.mitigations.clone();
                                 view! {
-                                    <div class="challenge-item">{text}</div>
+                                    <div class=class title=severity_label(c.severity)>
+                                        {text}
+                                        {(!mitigations.is_empty()).then(|| view! {
+                                            <ul class="mitigation-list">
+                                                {mitigations.into_iter().map(|m| view! {
+                                                    <li class="mitigation-item">
+                                                        <span class="mitigation-category">
+                                                            {mitigation_category_label(m.category)}
+                                                        </span>
+                                                        {m.text}
+                                                        {m.url.map(|url| view! {
+                                                            " "
+                                                            <a
+                                                                class="mitigation-link"
+                                                                href=url
+                                                                target="_blank"
+                                                                rel="noopener noreferrer"
+                                                            >"↗"</a>
+                                                        })}
+                                                    </li>
+                                                }).collect::<Vec<_>>()}
+                                            </ul>
+                                        })}
+                                    </div>
                                 }
                             }).collect::<Vec<_>>()}
 
@@ -183,7 +349,7 @@ pub fn MigrationPanel(
                                 Some(view! {
                                     <div class="positives-title">"Advantages"</div>
                                     {r.positives.iter().map(|p| {
-                                        let text = p.clone();
+                                        let text = finding_text(p);
                                         view! {
                                             <div class="positive-item">{text}</div>
                                         }
@@ -196,6 +362,39 @@ pub fn MigrationPanel(
                     </div>
                 }
             }}
+
+            <WhatIf
+                source=source.clone()
+                dest=dest.clone()
+                src_edit=src_edit
+                set_src_edit=set_src_edit
+                dst_edit=dst_edit
+                set_dst_edit=set_dst_edit
+                src_mode=src_mode
+                dst_mode=dst_mode
+                ecosystems=ecosystems
+                baseline=baseline
+                report=report
+            />
+
+            <PairNotes
+                source_id=source.id.clone()
+                dest_id=dest.id.clone()
+                src_mode=src_mode
+                dst_mode=dst_mode
+                text=route_notes
+                set_notes=set_notes
+            />
+
+            <EffortEstimator report=report project=project set_project=set_project />
+
+            <MigrationPlanView
+                report=report
+                source=src_edit
+                dest=dst_edit
+                src_mode=src_mode
+                dst_mode=dst_mode
+            />
         </div>
     }
 }
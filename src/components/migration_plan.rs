@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use leptos::prelude::*;
+
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::plan::{generate_plan, PlanSection};
+use bc_techmap_core::data::scoring::MigrationReport;
+use bc_techmap_core::export::{markdown_data_url, plan_markdown_issues};
+use bc_techmap_core::text::{plan_section_name, plan_step_detail, plan_step_title};
+
+#[component]
+pub fn MigrationPlanView(
+    report: Signal<MigrationReport>,
+    #[prop(into)] source: Signal<Ecosystem>,
+    #[prop(into)] dest: Signal<Ecosystem>,
+    src_mode: ReadSignal<Option<String>>,
+    dst_mode: ReadSignal<Option<String>>,
+) -> impl IntoView {
+    let file_name = format!(
+        "migration-plan-{}-{}.md",
+        source.with_untracked(|s| s.id.clone()),
+        dest.with_untracked(|d| d.id.clone())
+    );
+
+    let plan = Signal::derive(move || {
+        source.with(|s| {
+            dest.with(|d| {
+                generate_plan(
+                    &report.get(),
+                    s,
+                    d,
+                    src_mode.get().as_deref(),
+                    dst_mode.get().as_deref(),
+                )
+            })
+        })
+    });
+
+    // Checked steps, keyed by title so ticks survive unrelated re-renders.
+    let done = RwSignal::new(HashSet::<String>::new());
+
+    let export_href = move || {
+        source.with(|s| dest.with(|d| markdown_data_url(&plan_markdown_issues(&plan.get(), s, d))))
+    };
+
+    view! {
+        <div class="plan">
+            <div class="plan-header">
+                <span class="plan-title">"Migration Plan"</span>
+                <a class="plan-export" href=export_href download=file_name>"Export .md"</a>
+            </div>
+
+            {move || {
+                let p = plan.get();
+                PlanSection::ALL.iter().filter_map(|&section| {
+                    let steps: Vec<_> = p.steps.iter().filter(|s| s.kind.section() == section).cloned().collect();
+                    if steps.is_empty() {
+                        return None;
+                    }
+                    Some(view! {
+                        <div class="plan-section">
+                            <div class="plan-section-title">{plan_section_name(section)}</div>
+                            {steps.into_iter().map(|step| {
+                                let title = plan_step_title(&step);
+                                let detail = plan_step_detail(&step);
+                                let key = title.clone();
+                                let toggle_key = title.clone();
+                                view! {
+                                    <label class="plan-step" title=detail>
+                                        <input
+                                            type="checkbox"
+                                            prop:checked=move || done.with(|d| d.contains(&key))
+                                            on:change=move |_| done.update(|d| {
+                                                if !d.remove(&toggle_key) {
+                                                    d.insert(toggle_key.clone());
+                                                }
+                                            })
+                                        />
+                                        <span class="plan-step-title">{title}</span>
+                                    </label>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    })
+                }).collect::<Vec<_>>()
+            }}
+        </div>
+    }
+}
@@ -1,12 +1,14 @@
 use leptos::prelude::*;
 
 use crate::app::AppState;
-use crate::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::filter::Filter;
 
 #[component]
 pub fn MobileView(
     ecosystems: Vec<Ecosystem>,
     state: ReadSignal<AppState>,
+    filter: ReadSignal<Filter>,
     #[prop(into)] on_card_click: Callback<String>,
 ) -> impl IntoView {
     let cards = ecosystems
@@ -20,6 +22,17 @@ pub fn MobileView(
             let evm_compat = eco.evm_compatibility.clone();
             let chain_layer = eco.chain_layer.clone();
 
+            // Rows that don't match the filters are hidden rather than dimmed.
+            let row_style = move || {
+                if filter.with(|f| f.matches(&eco)) {
+                    ""
+                } else {
+                    "display: none;"
+                }
+            };
+
+            let aria_label = format!("{}: {}; VM {}", name, languages, vm);
+
             let click_id = id.clone();
             let on_click = move |_: web_sys::MouseEvent| {
                 on_card_click.run(click_id.clone());
@@ -56,7 +69,16 @@ pub fn MobileView(
             };
 
             view! {
-                <div class=card_class on:click=on_click>
+                <div
+                    class=card_class
+                    style=row_style
+                    data-eco-id=id
+                    data-nav=""
+                    role="button"
+                    tabindex="0"
+                    aria-label=aria_label
+                    on:click=on_click
+                >
                     <div class="mobile-card-header">
                         <span class="mobile-card-name">{name}</span>
                         <div class="eco-card-badges">
@@ -1,6 +1,19 @@
 pub mod arrow;
 pub mod canvas;
+pub mod command_palette;
+pub mod dataset_menu;
 pub mod ecosystem_card;
+pub mod ecosystem_editor;
+pub mod effort_estimator;
+pub mod export_menu;
+pub mod filter_bar;
+pub mod history_sidebar;
+pub mod map_view;
 pub mod migration_panel;
+pub mod migration_plan;
 pub mod mobile_view;
+pub mod pair_notes;
 pub mod ring_view;
+pub mod weights_control;
+pub mod what_if;
+pub mod whats_new;
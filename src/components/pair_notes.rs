@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+
+use crate::notes::Notes;
+
+/// Free-text notes for the comparison on screen, saved as they are typed.
+/// Switching deploy modes switches to that route's notes.
+#[component]
+pub fn PairNotes(
+    source_id: String,
+    dest_id: String,
+    src_mode: ReadSignal<Option<String>>,
+    dst_mode: ReadSignal<Option<String>>,
+    /// The current route's notes.
+    text: Signal<String>,
+    set_notes: WriteSignal<Notes>,
+) -> impl IntoView {
+    let source_id = StoredValue::new(source_id);
+    let dest_id = StoredValue::new(dest_id);
+
+    let on_input = move |ev| {
+        let value = event_target_value(&ev);
+        let (s, d) = (src_mode.get_untracked(), dst_mode.get_untracked());
+        set_notes.update(|n| {
+            n.set(
+                &source_id.get_value(),
+                &dest_id.get_value(),
+                s.as_deref(),
+                d.as_deref(),
+                value,
+            )
+        });
+    };
+
+    view! {
+        <div class="pair-notes">
+            <label class="effort-title" for="pair-notes-text">"Notes"</label>
+            <textarea
+                id="pair-notes-text"
+                class="pair-notes-text"
+                rows="4"
+                placeholder="Team context, e.g. \"we already have a Substrate dev\" or \"grant pending\""
+                prop:value=move || text.get()
+                on:input=on_input
+            ></textarea>
+            <div class="pair-notes-hint">"Saved in this browser and included in MD/JSON exports."</div>
+        </div>
+    }
+}
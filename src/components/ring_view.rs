@@ -6,12 +6,15 @@ use crate::app::AppState;
 use crate::components::arrow::CARD_WIDTH;
 use crate::components::arrow::CARD_HEIGHT;
 use crate::components::ecosystem_card::EcosystemCard;
-use crate::data::ecosystem::Ecosystem;
-use crate::data::scoring::compute_migration;
+use crate::notes::Notes;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::scoring::{
+    compute_migration_weighted, difficulty_band, Weights, DIFFICULTY_LABELS,
+};
+use bc_techmap_core::filter::Filter;
 
 /// Ring line radii — pushed out so innermost clears the center card.
 const RING_RADII: [f64; 5] = [150.0, 240.0, 330.0, 420.0, 510.0];
-const RING_LABELS: [&str; 5] = ["Trivial", "Easy", "Moderate", "Hard", "Extreme"];
 /// Stroke colors for the ring lines (palette: lighter → darker, red for extreme).
 const RING_COLORS: [&str; 5] = ["#92ccd6", "#2897a3", "#1bb5ce", "#032144", "#85241e"];
 
@@ -33,17 +36,7 @@ fn band_bounds() -> [(f64, f64); 5] {
     ]
 }
 
-fn score_to_ring(score: f64) -> usize {
-    match score {
-        x if x < 0.2 => 0,
-        x if x < 0.4 => 1,
-        x if x < 0.6 => 2,
-        x if x < 0.8 => 3,
-        _ => 4,
-    }
-}
-
-fn ring_difficulty_class(ring: usize) -> &'static str {
+pub fn ring_difficulty_class(ring: usize) -> &'static str {
     match ring {
         0 => "difficulty-trivial",
         1 => "difficulty-easy",
@@ -57,6 +50,9 @@ fn ring_difficulty_class(ring: usize) -> &'static str {
 pub fn RingView(
     ecosystems: Vec<Ecosystem>,
     state: ReadSignal<AppState>,
+    weights: ReadSignal<Weights>,
+    filter: ReadSignal<Filter>,
+    notes: ReadSignal<Notes>,
     #[prop(into)] on_card_click: Callback<String>,
     on_canvas_click: impl Fn() + 'static + Copy,
 ) -> impl IntoView {
@@ -122,9 +118,19 @@ pub fn RingView(
                                         let eco_name = eco.name.clone();
                                         let on_click = on_card_click;
                                         let click_id = eco.id.clone();
+                                        let eco_for_filter = eco.clone();
+                                        let btn_class = move || {
+                                            if filter.with(|f| f.matches(&eco_for_filter)) {
+                                                "ring-idle-btn"
+                                            } else {
+                                                "ring-idle-btn dimmed"
+                                            }
+                                        };
                                         view! {
                                             <button
-                                                class="ring-idle-btn"
+                                                class=btn_class
+                                                data-eco-id=click_id.clone()
+                                                data-nav=""
                                                 on:click=move |_| on_click.run(click_id.clone())
                                             >
                                                 {eco_name}
@@ -151,8 +157,9 @@ pub fn RingView(
                         if let Some(src) = source {
                             let mut ring_buckets: Vec<Vec<(Ecosystem, f64)>> = vec![vec![]; 5];
                             for eco in &others {
-                                let report = compute_migration(&src, eco, None, None);
-                                let ring_idx = score_to_ring(report.overall);
+                                let report =
+                                    compute_migration_weighted(&src, eco, None, None, &weights.get());
+                                let ring_idx = difficulty_band(report.overall);
                                 ring_buckets[ring_idx].push((eco.clone(), report.overall));
                             }
 
@@ -160,6 +167,9 @@ pub fn RingView(
                             let center_y = cy - CARD_HEIGHT / 2.0;
 
                             let src_clone = src.clone();
+                            let src_for_filter = src.clone();
+                            let center_dimmed =
+                                Signal::derive(move || !filter.with(|f| f.matches(&src_for_filter)));
                             let src_id = src.id.clone();
                             let is_center_selected = Signal::derive(move || {
                                 matches!(state.get(),
@@ -191,10 +201,15 @@ pub fn RingView(
 
                                     let is_active_dest = eco_id == *dest_id.as_deref().unwrap_or("");
 
-                                    let node_class = if is_active_dest {
-                                        format!("ring-node active {}", diff_class)
-                                    } else {
-                                        format!("ring-node {}", diff_class)
+                                    let eco_for_filter = eco.clone();
+                                    let node_class = move || {
+                                        let active = if is_active_dest { " active" } else { "" };
+                                        let dimmed = if filter.with(|f| f.matches(&eco_for_filter)) {
+                                            ""
+                                        } else {
+                                            " dimmed"
+                                        };
+                                        format!("ring-node{} {}{}", active, diff_class, dimmed)
                                     };
 
                                     let style = format!(
@@ -202,18 +217,38 @@ pub fn RingView(
                                         nx, ny
                                     );
 
+                                    let aria_label = format!(
+                                        "{}: {} migration from {}",
+                                        eco_name, DIFFICULTY_LABELS[ring_idx], src.name
+                                    );
+
+                                    let notes_src = src.id.clone();
+                                    let notes_dst = eco_id.clone();
+                                    let has_notes = move || {
+                                        notes.with(|n| n.marks(&notes_dst, Some(&notes_src)))
+                                    };
+
                                     let on_click = on_card_click;
                                     let click_id = eco_id.clone();
                                     ring_node_views.push(view! {
                                         <div
                                             class=node_class
                                             style=style
+                                            data-eco-id=eco_id.clone()
+                                            data-nav=""
+                                            role="button"
+                                            tabindex="0"
+                                            aria-label=aria_label
+                                            aria-pressed=is_active_dest.to_string()
                                             on:click=move |ev: web_sys::MouseEvent| {
                                                 ev.stop_propagation();
                                                 on_click.run(click_id.clone());
                                             }
                                         >
                                             <span class="ring-node-name">{eco_name}</span>
+                                            <Show when=has_notes>
+                                                <span class="notes-marker" title="Has notes">"✎"</span>
+                                            </Show>
                                         </div>
                                     });
                                 }
@@ -222,7 +257,7 @@ pub fn RingView(
                             // ── SVG: ring strokes + labels only (bands are CSS radial-gradient) ──
                             let bounds = band_bounds();
                             let ring_lines: Vec<_> = RING_RADII.iter().enumerate().map(|(i, &r)| {
-                                let label = RING_LABELS[i];
+                                let label = DIFFICULTY_LABELS[i];
                                 let color = RING_COLORS[i];
                                 let (inner, outer) = bounds[i];
                                 let label_r = (inner + outer) / 2.0;
@@ -254,7 +289,8 @@ pub fn RingView(
                                     ecosystem=src_clone
                                     is_selected=is_center_selected
                                     on_click=on_card_click
-                                    position_override=(center_x, center_y)
+                                    position=[center_x, center_y]
+                                    dimmed=center_dimmed
                                 />
                                 {ring_node_views}
                             }.into_any()
@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+
+use bc_techmap_core::data::scoring::{Dimension, Weights, WEIGHT_PROFILES};
+
+/// Header dropdown for picking a weight profile or tuning each dimension.
+#[component]
+pub fn WeightsControl(
+    weights: ReadSignal<Weights>,
+    set_weights: WriteSignal<Weights>,
+) -> impl IntoView {
+    let (open, set_open) = signal(false);
+
+    let label = move || {
+        let name = weights.get().profile_name().unwrap_or("custom");
+        format!("WEIGHTS: {}", name.to_uppercase())
+    };
+
+    view! {
+        <div class="weights-control">
+            <button
+                class=move || if open.get() { "view-btn active" } else { "view-btn" }
+                on:click=move |_| set_open.update(|o| *o = !*o)
+            >
+                {label}
+            </button>
+            <Show when=move || open.get()>
+                <div class="weights-dropdown">
+                    <div class="weights-profiles">
+                        {WEIGHT_PROFILES.iter().map(|&name| view! {
+                            <button
+                                class=move || {
+                                    if weights.get().profile_name() == Some(name) {
+                                        "mode-btn active"
+                                    } else {
+                                        "mode-btn"
+                                    }
+                                }
+                                on:click=move |_| {
+                                    if let Some(w) = Weights::profile(name) {
+                                        set_weights.set(w);
+                                    }
+                                }
+                            >
+                                {name}
+                            </button>
+                        }).collect::<Vec<_>>()}
+                    </div>
+                    {Dimension::ALL.iter().map(|&dim| view! {
+                        <label class="weights-field">
+                            <span class="weights-field-label">{dim.name()}</span>
+                            <input
+                                class="weights-slider"
+                                type="range"
+                                min="0"
+                                max="50"
+                                prop:value=move || {
+                                    format!("{:.0}", weights.get().get(dim) * 100.0)
+                                }
+                                on:input=move |ev| {
+                                    if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+                                        set_weights.update(|w| w.set(dim, v / 100.0));
+                                    }
+                                }
+                            />
+                            <span class="weights-field-value">
+                                {move || format!("{:.0}", weights.get().get(dim) * 100.0)}
+                            </span>
+                        </label>
+                    }).collect::<Vec<_>>()}
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,293 @@
+use leptos::prelude::*;
+
+use bc_techmap_core::data::dataset::{ecosystem_patches, overlay_toml};
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::rules::EVM_COMPATIBILITY;
+use bc_techmap_core::data::scoring::{effective_languages, MigrationReport};
+use bc_techmap_core::export::data_url;
+
+const RATINGS: [&str; 5] = ["1", "2", "3", "4", "5"];
+
+/// The attributes that feed scoring and can be tried out in the panel.
+#[derive(Clone, Copy, PartialEq)]
+enum Attr {
+    Languages,
+    Vm,
+    TxModel,
+    EvmCompat,
+    Deploy,
+    Tooling,
+    Docs,
+    Funding,
+    L2,
+}
+
+impl Attr {
+    const ALL: [Attr; 9] = [
+        Attr::Languages,
+        Attr::Vm,
+        Attr::TxModel,
+        Attr::EvmCompat,
+        Attr::Deploy,
+        Attr::Tooling,
+        Attr::Docs,
+        Attr::Funding,
+        Attr::L2,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Attr::Languages => "Languages",
+            Attr::Vm => "VM",
+            Attr::TxModel => "Tx model",
+            Attr::EvmCompat => "EVM",
+            Attr::Deploy => "Deploy",
+            Attr::Tooling => "Tooling",
+            Attr::Docs => "Docs",
+            Attr::Funding => "Funding",
+            Attr::L2 => "L2",
+        }
+    }
+
+    /// Choices offered, or `None` for free text. Transaction models and
+    /// deployment options are the ones `ecosystems` use.
+    fn choices(self, ecosystems: &[Ecosystem]) -> Option<Vec<String>> {
+        let used = |value: fn(&Ecosystem) -> String| {
+            let mut values: Vec<String> = ecosystems.iter().map(value).collect();
+            values.sort();
+            values.dedup();
+            values
+        };
+        match self {
+            Attr::Languages | Attr::Vm => None,
+            Attr::TxModel => Some(used(|e| e.transaction_model.clone())),
+            Attr::EvmCompat => Some(EVM_COMPATIBILITY.iter().map(|c| c.to_string()).collect()),
+            Attr::Deploy => Some(used(|e| e.deployment_options.join(", "))),
+            Attr::Tooling | Attr::Docs | Attr::Funding | Attr::L2 => {
+                Some(RATINGS.iter().map(|r| r.to_string()).collect())
+            }
+        }
+    }
+
+    /// Languages are the ones scored for the selected deploy mode.
+    fn get(self, eco: &Ecosystem, mode: Option<&str>) -> String {
+        match self {
+            Attr::Languages => effective_languages(eco, mode).join(", "),
+            Attr::Vm => eco.vm.clone(),
+            Attr::TxModel => eco.transaction_model.clone(),
+            Attr::EvmCompat => eco.evm_compatibility.clone(),
+            Attr::Deploy => eco.deployment_options.join(", "),
+            Attr::Tooling => eco.tooling_maturity.to_string(),
+            Attr::Docs => eco.doc_quality.to_string(),
+            Attr::Funding => eco.ecosystem_funding.to_string(),
+            Attr::L2 => eco.l2_maturity.to_string(),
+        }
+    }
+
+    fn set(self, eco: &mut Ecosystem, mode: Option<&str>, value: &str) {
+        let rating = || value.parse::<u8>().unwrap_or(1).clamp(1, 5);
+        let list = || -> Vec<String> {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        match self {
+            Attr::Languages => {
+                let languages = list();
+                if languages.is_empty() {
+                    return;
+                }
+                let mode_entry = mode
+                    .zip(eco.deploy_modes.as_mut())
+                    .and_then(|(m, modes)| modes.get_mut(m));
+                match mode_entry {
+                    Some(dm) => dm.languages = languages,
+                    None => eco.languages = languages,
+                }
+            }
+            Attr::Vm if !value.trim().is_empty() => eco.vm = value.trim().to_string(),
+            Attr::Vm => {}
+            Attr::TxModel => eco.transaction_model = value.to_string(),
+            Attr::EvmCompat => eco.evm_compatibility = value.to_string(),
+            Attr::Deploy => {
+                let options = list();
+                if !options.is_empty() {
+                    eco.deployment_options = options;
+                }
+            }
+            Attr::Tooling => eco.tooling_maturity = rating(),
+            Attr::Docs => eco.doc_quality = rating(),
+            Attr::Funding => eco.ecosystem_funding = rating(),
+            Attr::L2 => eco.l2_maturity = rating(),
+        }
+    }
+}
+
+/// One side's chips. Changed values are highlighted.
+fn chip_row(
+    short: String,
+    original: StoredValue<Ecosystem>,
+    edit: ReadSignal<Ecosystem>,
+    set_edit: WriteSignal<Ecosystem>,
+    mode: ReadSignal<Option<String>>,
+    ecosystems: Memo<Vec<Ecosystem>>,
+) -> impl IntoView {
+    let chips = Attr::ALL
+        .iter()
+        .map(|&attr| {
+            let value = move || edit.with(|e| attr.get(e, mode.get().as_deref()));
+            let changed = move || {
+                let m = mode.get();
+                original.with_value(|o| attr.get(o, m.as_deref())) != value()
+            };
+            let on_change = move |ev| {
+                let v = event_target_value(&ev);
+                let m = mode.get_untracked();
+                set_edit.update(|e| attr.set(e, m.as_deref(), &v));
+            };
+            let class = move || {
+                if changed() {
+                    "what-if-chip changed"
+                } else {
+                    "what-if-chip"
+                }
+            };
+            let aria_label = format!("{} {}", short, attr.label());
+            let choices = Memo::new(move |_| ecosystems.with(|all| attr.choices(all)));
+
+            let input = if choices.with_untracked(Option::is_some) {
+                view! {
+                    <select
+                        class="what-if-input"
+                        aria-label=aria_label
+                        prop:value=value
+                        on:change=on_change
+                    >
+                        {move || {
+                            // Keep values outside the usual set selectable.
+                            let choices = choices.get().unwrap_or_default();
+                            let current = value();
+                            let extra = (!choices.contains(&current)).then_some(current);
+                            extra
+                                .into_iter()
+                                .chain(choices)
+                                .map(|c| view! { <option value=c.clone()>{c.clone()}</option> })
+                                .collect::<Vec<_>>()
+                        }}
+                    </select>
+                }
+                .into_any()
+            } else {
+                view! {
+                    <input
+                        class="what-if-input"
+                        type="text"
+                        aria-label=aria_label
+                        prop:value=value
+                        on:change=on_change
+                    />
+                }
+                .into_any()
+            };
+
+            view! {
+                <label class=class>
+                    <span class="what-if-chip-label">{attr.label()}</span>
+                    {input}
+                </label>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="what-if-row">
+            <span class="mode-label">{short}</span>
+            <div class="what-if-chips">{chips}</div>
+        </div>
+    }
+}
+
+/// Try other attribute values for either side of the comparison: the report
+/// recomputes live, the change from the dataset's verdict is shown, and the
+/// edits can be exported as a TOML overlay to propose them.
+#[component]
+pub fn WhatIf(
+    source: Ecosystem,
+    dest: Ecosystem,
+    src_edit: ReadSignal<Ecosystem>,
+    set_src_edit: WriteSignal<Ecosystem>,
+    dst_edit: ReadSignal<Ecosystem>,
+    set_dst_edit: WriteSignal<Ecosystem>,
+    src_mode: ReadSignal<Option<String>>,
+    dst_mode: ReadSignal<Option<String>>,
+    /// Everything on the map, for the choices offered.
+    ecosystems: Memo<Vec<Ecosystem>>,
+    /// The report for the dataset's own values.
+    baseline: Signal<MigrationReport>,
+    report: Signal<MigrationReport>,
+) -> impl IntoView {
+    let file_name = format!("what-if-{}-{}.toml", source.id, dest.id);
+    let (src_short, dst_short) = (source.short.clone(), dest.short.clone());
+    let source = StoredValue::new(source);
+    let dest = StoredValue::new(dest);
+
+    let patches = Memo::new(move |_| {
+        let mut patches = source.with_value(|o| src_edit.with(|e| ecosystem_patches(o, e)));
+        patches.extend(dest.with_value(|o| dst_edit.with(|e| ecosystem_patches(o, e))));
+        patches
+    });
+    let edited = move || patches.with(|p| !p.is_empty());
+    let patch_href = move || patches.with(|p| data_url("application/toml", &overlay_toml(p)));
+
+    let reset = move |_| {
+        set_src_edit.set(source.get_value());
+        set_dst_edit.set(dest.get_value());
+    };
+
+    let delta = move || {
+        let (b, r) = (baseline.get(), report.get());
+        let diff = (r.overall - b.overall) * 5.0;
+        let summary = format!(
+            "Dataset {:.1}/5 {} → {:.1}/5 {} ({:+.1})",
+            b.overall * 5.0,
+            b.difficulty_label,
+            r.overall * 5.0,
+            r.difficulty_label,
+            diff
+        );
+        let dimensions = r
+            .dimensions
+            .iter()
+            .filter_map(|d| {
+                let before = b.score(d.dimension);
+                ((d.score - before).abs() > 1e-9).then(|| {
+                    view! {
+                        <li>{format!("{}: {:.2} → {:.2} ({:+.2})", d.name, before, d.score, d.score - before)}</li>
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        view! {
+            <div class="what-if-delta" aria-live="polite">{summary}</div>
+            <ul class="what-if-dimensions">{dimensions}</ul>
+        }
+    };
+
+    view! {
+        <div class="what-if">
+            <div class="effort-title">"What if"</div>
+            {chip_row(src_short, source, src_edit, set_src_edit, src_mode, ecosystems)}
+            {chip_row(dst_short, dest, dst_edit, set_dst_edit, dst_mode, ecosystems)}
+            <Show when=edited>
+                {delta}
+                <div class="what-if-actions">
+                    <a class="export-btn" href=patch_href download=file_name.clone()>"TOML patch"</a>
+                    <button class="export-btn" on:click=reset>"Reset"</button>
+                </div>
+            </Show>
+        </div>
+    }
+}
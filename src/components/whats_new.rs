@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+
+use crate::last_visit;
+use bc_techmap_core::data::dataset::{dataset_release, releases_since, DatasetRelease};
+
+/// Changelog entries added since the visitor's last visit. Nothing is shown
+/// on a first visit; either way the current release is remembered.
+#[component]
+pub fn WhatsNew() -> impl IntoView {
+    let releases: &'static [DatasetRelease] = last_visit::load()
+        .filter(|seen| seen != dataset_release())
+        .map(|seen| releases_since(&seen))
+        .unwrap_or(&[]);
+    last_visit::save(dataset_release());
+
+    let (open, set_open) = signal(!releases.is_empty());
+
+    let entries = releases
+        .iter()
+        .map(|r| {
+            view! {
+                <li class="whats-new-release">
+                    <span class="whats-new-version">{r.version}" · "{r.date}</span>
+                    <ul>
+                        {r.changes.iter().map(|c| view! { <li>{*c}</li> }).collect::<Vec<_>>()}
+                    </ul>
+                </li>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <Show when=move || open.get()>
+            <div class="whats-new" role="status">
+                <button
+                    class="history-pin whats-new-close"
+                    aria-label="Dismiss dataset changes"
+                    on:click=move |_| set_open.set(false)
+                >
+                    "×"
+                </button>
+                <div class="effort-title">"What changed since your last visit"</div>
+                <ul class="whats-new-list">{entries.clone()}</ul>
+            </div>
+        </Show>
+    }
+}
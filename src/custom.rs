@@ -0,0 +1,39 @@
+//! Ecosystems added in the browser, kept in `localStorage` and shown
+//! alongside the bundled dataset.
+
+use crate::storage;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+
+const STORAGE_KEY: &str = "bc-techmap.custom-ecosystems";
+/// Grid group for custom ecosystems that don't name one.
+pub const CUSTOM_GROUP: &str = "custom";
+
+/// The stored ecosystems that still pass validation against `dataset`;
+/// ones whose id the dataset now uses, or that break a rule, are dropped.
+pub fn load(dataset: &[Ecosystem]) -> Vec<Ecosystem> {
+    let stored: Vec<Ecosystem> = storage::load_json(STORAGE_KEY).unwrap_or_default();
+    let mut others = dataset.to_vec();
+    let mut kept = Vec::new();
+    for eco in stored {
+        if eco.validate(&others).is_empty() {
+            others.push(eco.clone());
+            kept.push(eco);
+        }
+    }
+    kept
+}
+
+/// Ids of `custom` ecosystems that `dataset` also uses, comma-separated,
+/// or `None` when there are none.
+pub fn taken_ids(custom: &[Ecosystem], dataset: &[Ecosystem]) -> Option<String> {
+    let taken: Vec<&str> = custom
+        .iter()
+        .filter(|own| dataset.iter().any(|e| e.id == own.id))
+        .map(|own| own.id.as_str())
+        .collect();
+    (!taken.is_empty()).then(|| taken.join(", "))
+}
+
+pub fn save(custom: &[Ecosystem]) {
+    storage::save_json(STORAGE_KEY, custom);
+}
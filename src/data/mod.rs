@@ -1,2 +0,0 @@
-pub mod ecosystem;
-pub mod scoring;
@@ -0,0 +1,52 @@
+//! A dataset imported in the browser and field overlays on top of it, kept
+//! in `localStorage`. Without either, the bundled dataset is used.
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+use bc_techmap_core::data::dataset::{apply_overlay, Patch};
+use bc_techmap_core::data::ecosystem::Ecosystem;
+
+const STORAGE_KEY: &str = "bc-techmap.dataset";
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatasetState {
+    /// Replaces the bundled ecosystems when set.
+    pub replacement: Option<Vec<Ecosystem>>,
+    /// Applied in order on top of the bundled or replacement ecosystems.
+    pub patches: Vec<Patch>,
+}
+
+impl DatasetState {
+    pub fn is_modified(&self) -> bool {
+        *self != DatasetState::default()
+    }
+
+    /// The ecosystems before overlays: the replacement or the bundled set.
+    pub fn base(&self, bundled: &[Ecosystem]) -> Vec<Ecosystem> {
+        self.replacement.clone().unwrap_or_else(|| bundled.to_vec())
+    }
+
+    /// The ecosystems with overlays applied. Patches were checked when
+    /// added, so a failure only drops the overlays.
+    pub fn effective(&self, bundled: &[Ecosystem]) -> Vec<Ecosystem> {
+        let base = self.base(bundled);
+        let mut patched = base.clone();
+        match apply_overlay(&mut patched, &self.patches) {
+            Ok(()) => patched,
+            Err(_) => base,
+        }
+    }
+}
+
+pub fn load() -> DatasetState {
+    storage::load_json(STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn save(state: &DatasetState) {
+    if state.is_modified() {
+        storage::save_json(STORAGE_KEY, state);
+    } else {
+        storage::remove(STORAGE_KEY);
+    }
+}
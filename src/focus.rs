@@ -0,0 +1,178 @@
+//! Keyboard focus for the web app: arrow keys move spatially between map
+//! items, Enter/Space activate them, and Tab stays inside the open panel.
+
+use leptos::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+/// Marks elements that arrow keys move between. They also carry
+/// `data-eco-id`, which is how focus finds them again after a re-render.
+const NAV_SELECTOR: &str = "[data-nav]";
+
+const FOCUSABLE: &str =
+    "button, a[href], input, select, textarea, [tabindex]:not([tabindex=\"-1\"])";
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window().and_then(|w| w.document())
+}
+
+fn focus(el: &Element) {
+    if let Some(el) = el.dyn_ref::<HtmlElement>() {
+        let _ = el.focus();
+    }
+}
+
+fn center(el: &Element) -> Option<(f64, f64)> {
+    let r = el.get_bounding_client_rect();
+    // Hidden elements (e.g. filtered-out mobile rows) have no box.
+    (r.width() > 0.0).then(|| (r.x() + r.width() / 2.0, r.y() + r.height() / 2.0))
+}
+
+fn elements(root: &impl AsRef<web_sys::Node>, selector: &str) -> Vec<Element> {
+    let list = match root.as_ref().dyn_ref::<Element>() {
+        Some(el) => el.query_selector_all(selector),
+        None => match root.as_ref().dyn_ref::<web_sys::Document>() {
+            Some(doc) => doc.query_selector_all(selector),
+            None => return Vec::new(),
+        },
+    };
+    let Ok(list) = list else {
+        return Vec::new();
+    };
+    (0..list.length())
+        .filter_map(|i| list.item(i)?.dyn_into::<Element>().ok())
+        .collect()
+}
+
+/// Arrow keys: focus the nearest navigable item in that direction, favoring
+/// items in line with the current one. Returns whether the key was used.
+fn move_spatially(ev: &KeyboardEvent, active: &Element) -> bool {
+    let (dx, dy) = match ev.key().as_str() {
+        "ArrowLeft" => (-1.0, 0.0),
+        "ArrowRight" => (1.0, 0.0),
+        "ArrowUp" => (0.0, -1.0),
+        "ArrowDown" => (0.0, 1.0),
+        _ => return false,
+    };
+    let (Some(doc), Some((ax, ay))) = (document(), center(active)) else {
+        return false;
+    };
+
+    let mut best: Option<(f64, Element)> = None;
+    for el in elements(&doc, NAV_SELECTOR) {
+        if el == *active {
+            continue;
+        }
+        let Some((x, y)) = center(&el) else {
+            continue;
+        };
+        let along = (x - ax) * dx + (y - ay) * dy;
+        if along < 1.0 {
+            continue;
+        }
+        let across = ((x - ax) * dy - (y - ay) * dx).abs();
+        let cost = along + 2.0 * across;
+        if best.as_ref().is_none_or(|(c, _)| cost < *c) {
+            best = Some((cost, el));
+        }
+    }
+    if let Some((_, el)) = best {
+        focus(&el);
+    }
+    true
+}
+
+/// Keep Tab and Shift-Tab cycling inside `container`.
+fn trap_tab(ev: &KeyboardEvent, container: &Element, active: Option<&Element>) {
+    let items: Vec<Element> = elements(container, FOCUSABLE)
+        .into_iter()
+        .filter(|el| center(el).is_some())
+        .collect();
+    let (Some(first), Some(last)) = (items.first(), items.last()) else {
+        return;
+    };
+    let inside = active.is_some_and(|a| container.contains(Some(a)));
+    let target = match (inside, ev.shift_key()) {
+        (false, false) => first,
+        (false, true) => last,
+        (true, false) if active == Some(last) => first,
+        (true, true) if active == Some(first) => last,
+        _ => return,
+    };
+    ev.prevent_default();
+    focus(target);
+}
+
+/// Window-level keydown handler.
+pub fn handle_keydown(ev: &KeyboardEvent) {
+    let Some(doc) = document() else {
+        return;
+    };
+    let active = doc.active_element();
+
+    if ev.key() == "Tab" {
+        // The palette and editor sit above the panel when open.
+        let dialog = [".command-palette", ".ecosystem-editor", ".migration-panel"]
+            .iter()
+            .find_map(|sel| doc.query_selector(sel).ok().flatten());
+        if let Some(dialog) = dialog {
+            trap_tab(ev, &dialog, active.as_ref());
+        }
+        return;
+    }
+
+    let Some(active) = active.filter(|a| a.matches(NAV_SELECTOR).unwrap_or(false)) else {
+        return;
+    };
+    match ev.key().as_str() {
+        // Buttons already activate natively.
+        "Enter" | " " if active.tag_name() != "BUTTON" => {
+            ev.prevent_default();
+            if let Some(el) = active.dyn_ref::<HtmlElement>() {
+                el.click();
+            }
+        }
+        _ => {
+            if move_spatially(ev, &active) {
+                ev.prevent_default();
+            }
+        }
+    }
+}
+
+/// The ecosystem id of the focused navigable item, if any.
+pub fn focused_eco_id() -> Option<String> {
+    document()?
+        .active_element()?
+        .closest(NAV_SELECTOR)
+        .ok()??
+        .get_attribute("data-eco-id")
+}
+
+/// Focus an ecosystem's item again (it may have been re-rendered), unless
+/// focus has since moved into a panel.
+pub fn restore_focus(eco_id: &str) {
+    let Some(doc) = document() else {
+        return;
+    };
+    let in_panel = doc
+        .active_element()
+        .and_then(|a| a.closest(".migration-panel").ok().flatten())
+        .is_some();
+    if in_panel {
+        return;
+    }
+    let selector = format!("{}[data-eco-id=\"{}\"]", NAV_SELECTOR, eco_id);
+    if let Some(el) = elements(&doc, &selector)
+        .into_iter()
+        .find(|el| center(el).is_some())
+    {
+        focus(&el);
+    }
+}
+
+/// Focus the first focusable element inside `container`.
+pub fn focus_first(container: &Element) {
+    if let Some(el) = elements(container, FOCUSABLE).first() {
+        focus(el);
+    }
+}
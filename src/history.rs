@@ -0,0 +1,109 @@
+//! Recently viewed comparisons, with pins and back/forward, kept in
+//! `localStorage` across reloads.
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+use bc_techmap_core::data::scoring::Weights;
+
+const STORAGE_KEY: &str = "bc-techmap.history";
+/// Unpinned entries kept; pinned ones are never dropped.
+const MAX_RECENT: usize = 20;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub source: String,
+    pub dest: String,
+    pub src_mode: Option<String>,
+    pub dst_mode: Option<String>,
+    pub weights: Weights,
+    pub overall: f64,
+    pub difficulty_label: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl HistoryEntry {
+    pub fn same_pair(&self, other: &HistoryEntry) -> bool {
+        self.source == other.source && self.dest == other.dest
+    }
+
+    pub fn same_settings(&self, other: &HistoryEntry) -> bool {
+        self.same_pair(other)
+            && self.src_mode == other.src_mode
+            && self.dst_mode == other.dst_mode
+            && self.weights == other.weights
+    }
+}
+
+/// Newest first. `cursor` is the entry being viewed, which back/forward
+/// move without reordering.
+#[derive(Clone, Default, PartialEq)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+    pub cursor: usize,
+}
+
+impl History {
+    /// Record the comparison on screen. Tweaks to the entry being viewed
+    /// (modes, weights) update it in place unless it is pinned; anything
+    /// else becomes the newest entry.
+    pub fn record(&mut self, entry: HistoryEntry) {
+        if let Some(current) = self.entries.get_mut(self.cursor) {
+            if current.same_pair(&entry) && (!current.pinned || current.same_settings(&entry)) {
+                let pinned = current.pinned;
+                *current = HistoryEntry { pinned, ..entry };
+                return;
+            }
+        }
+        self.entries
+            .retain(|e| e.pinned || !e.same_settings(&entry));
+        self.entries.insert(0, entry);
+        self.cursor = 0;
+
+        let mut recent = 0;
+        self.entries.retain(|e| {
+            recent += usize::from(!e.pinned);
+            e.pinned || recent <= MAX_RECENT
+        });
+    }
+
+    pub fn toggle_pin(&mut self, index: usize) {
+        if let Some(entry) = self.entries.get_mut(index) {
+            entry.pinned = !entry.pinned;
+        }
+    }
+
+    /// Drop everything that isn't pinned.
+    pub fn clear_recent(&mut self) {
+        let current = self.entries.get(self.cursor).cloned();
+        self.entries.retain(|e| e.pinned);
+        self.cursor = current
+            .and_then(|c| self.entries.iter().position(|e| *e == c))
+            .unwrap_or(0);
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.cursor > 0 && !self.entries.is_empty()
+    }
+
+    /// Move the cursor and return the entry to show.
+    pub fn go(&mut self, index: usize) -> Option<HistoryEntry> {
+        let entry = self.entries.get(index)?.clone();
+        self.cursor = index;
+        Some(entry)
+    }
+}
+
+pub fn load() -> History {
+    let entries = storage::load_json(STORAGE_KEY).unwrap_or_default();
+    History { entries, cursor: 0 }
+}
+
+pub fn save(history: &History) {
+    storage::save_json(STORAGE_KEY, &history.entries);
+}
@@ -0,0 +1,15 @@
+//! The dataset release a visitor last saw, kept in `localStorage` for the
+//! "what changed" notice.
+
+use crate::storage;
+
+const STORAGE_KEY: &str = "bc-techmap.dataset-release";
+
+/// `None` on a first visit.
+pub fn load() -> Option<String> {
+    storage::load(STORAGE_KEY)
+}
+
+pub fn save(version: &str) {
+    storage::save(STORAGE_KEY, version);
+}
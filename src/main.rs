@@ -1,13 +1,28 @@
 mod app;
+mod cli;
 mod components;
-mod data;
-mod theme;
+mod custom;
+mod dataset;
+mod focus;
+mod history;
+mod last_visit;
+mod notes;
+#[cfg(not(target_arch = "wasm32"))]
+mod server;
+mod storage;
+#[cfg(not(target_arch = "wasm32"))]
+mod tui;
 
 use app::App;
 use leptos::prelude::*;
 
 pub fn main() {
-    #[cfg(debug_assertions)]
-    console_error_panic_hook::set_once();
-    mount_to_body(App);
+    // The wasm build is the web app; native builds run the CLI.
+    if cfg!(target_arch = "wasm32") {
+        #[cfg(debug_assertions)]
+        console_error_panic_hook::set_once();
+        mount_to_body(App);
+    } else {
+        cli::run();
+    }
 }
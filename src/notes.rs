@@ -0,0 +1,93 @@
+//! Team notes on comparisons, keyed by pair and deploy modes, kept in
+//! `localStorage` across reloads.
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+
+const STORAGE_KEY: &str = "bc-techmap.notes";
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub source: String,
+    pub dest: String,
+    pub src_mode: Option<String>,
+    pub dst_mode: Option<String>,
+    pub text: String,
+}
+
+impl Note {
+    fn is_for(
+        &self,
+        source: &str,
+        dest: &str,
+        src_mode: Option<&str>,
+        dst_mode: Option<&str>,
+    ) -> bool {
+        self.source == source
+            && self.dest == dest
+            && self.src_mode.as_deref() == src_mode
+            && self.dst_mode.as_deref() == dst_mode
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+pub struct Notes {
+    pub notes: Vec<Note>,
+}
+
+impl Notes {
+    pub fn get(
+        &self,
+        source: &str,
+        dest: &str,
+        src_mode: Option<&str>,
+        dst_mode: Option<&str>,
+    ) -> &str {
+        self.notes
+            .iter()
+            .find(|n| n.is_for(source, dest, src_mode, dst_mode))
+            .map_or("", |n| n.text.as_str())
+    }
+
+    /// Replace a comparison's note; blank text removes it.
+    pub fn set(
+        &mut self,
+        source: &str,
+        dest: &str,
+        src_mode: Option<&str>,
+        dst_mode: Option<&str>,
+        text: String,
+    ) {
+        self.notes
+            .retain(|n| !n.is_for(source, dest, src_mode, dst_mode));
+        if !text.trim().is_empty() {
+            self.notes.push(Note {
+                source: source.to_string(),
+                dest: dest.to_string(),
+                src_mode: src_mode.map(str::to_string),
+                dst_mode: dst_mode.map(str::to_string),
+                text,
+            });
+        }
+    }
+
+    /// Whether an ecosystem's card should show the notes marker: with a
+    /// source selected, if that pair has notes in any mode; otherwise if any
+    /// note mentions it.
+    pub fn marks(&self, eco_id: &str, source: Option<&str>) -> bool {
+        self.notes.iter().any(|n| match source {
+            Some(s) => n.source == s && n.dest == eco_id,
+            None => n.source == eco_id || n.dest == eco_id,
+        })
+    }
+}
+
+pub fn load() -> Notes {
+    let notes = storage::load_json(STORAGE_KEY).unwrap_or_default();
+    Notes { notes }
+}
+
+pub fn save(notes: &Notes) {
+    storage::save_json(STORAGE_KEY, &notes.notes);
+}
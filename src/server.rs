@@ -0,0 +1,285 @@
+//! Local HTTP JSON server (`bc-techmap serve`). Responses use the same
+//! types as the static API; the routes are described in `openapi.yaml`.
+
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use bc_techmap_core::api::{deploy_mode, CompareResponse, EcosystemList, RankResponse};
+use bc_techmap_core::data::dataset::DATASET_VERSION;
+use bc_techmap_core::data::ecosystem::Ecosystem;
+use bc_techmap_core::data::scoring::{Dimension, Weights, WEIGHT_PROFILES};
+use bc_techmap_core::filter::percent_decode;
+
+const OPENAPI: &str = include_str!("../openapi.yaml");
+
+/// Largest accepted request body.
+const MAX_BODY: u64 = 1 << 20;
+
+/// Body of `POST /compare`.
+#[derive(Deserialize)]
+struct CompareRequest {
+    src: String,
+    dst: String,
+    src_mode: Option<String>,
+    dst_mode: Option<String>,
+    /// Named weight preset; `weights` takes precedence.
+    profile: Option<String>,
+    weights: Option<Weights>,
+    /// Ecosystems that replace the built-in entry with the same id, or are
+    /// added alongside the dataset.
+    #[serde(default)]
+    ecosystems: Vec<Ecosystem>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse<'a> {
+    error: &'a str,
+}
+
+/// An error response: HTTP status and message.
+struct Error(u16, String);
+
+impl Error {
+    fn bad_request(msg: impl Into<String>) -> Self {
+        Error(400, msg.into())
+    }
+
+    fn not_found(msg: impl Into<String>) -> Self {
+        Error(404, msg.into())
+    }
+}
+
+impl From<String> for Error {
+    fn from(msg: String) -> Self {
+        Error::bad_request(msg)
+    }
+}
+
+/// A routed response body with its content type.
+struct Body {
+    content_type: &'static str,
+    text: String,
+}
+
+impl Body {
+    fn json<T: Serialize>(value: &T) -> Result<Self, Error> {
+        let text = serde_json::to_string_pretty(value).map_err(|e| Error(500, e.to_string()))?;
+        Ok(Body {
+            content_type: "application/json",
+            text,
+        })
+    }
+}
+
+/// Split a request URL into its path and decoded query parameters.
+fn split_url(url: &str) -> (&str, Vec<(String, String)>) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let params = query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (k, v) = p.split_once('=').unwrap_or((p, ""));
+            (percent_decode(k), percent_decode(v))
+        })
+        .collect();
+    (path, params)
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.as_str())
+        .filter(|v| !v.is_empty())
+}
+
+fn required<'a>(params: &'a [(String, String)], name: &str) -> Result<&'a str, Error> {
+    param(params, name)
+        .ok_or_else(|| Error::bad_request(format!("missing query parameter {:?}", name)))
+}
+
+fn find<'a>(ecosystems: &'a [Ecosystem], id: &str) -> Result<&'a Ecosystem, Error> {
+    ecosystems
+        .iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| Error::not_found(format!("unknown ecosystem {:?}", id)))
+}
+
+fn profile(name: Option<&str>) -> Result<Weights, Error> {
+    match name {
+        None => Ok(Weights::default()),
+        Some(name) => Weights::profile(name).ok_or_else(|| {
+            Error::bad_request(format!(
+                "unknown weight profile {:?} (options: {})",
+                name,
+                WEIGHT_PROFILES.join(", ")
+            ))
+        }),
+    }
+}
+
+fn compare(
+    ecosystems: &[Ecosystem],
+    src: &str,
+    dst: &str,
+    src_mode: Option<&str>,
+    dst_mode: Option<&str>,
+    weights: Weights,
+) -> Result<Body, Error> {
+    let src = find(ecosystems, src)?;
+    let dst = find(ecosystems, dst)?;
+    let src_mode = deploy_mode(src, src_mode)?;
+    let dst_mode = deploy_mode(dst, dst_mode)?;
+    Body::json(&CompareResponse::weighted(
+        src,
+        dst,
+        src_mode.as_deref(),
+        dst_mode.as_deref(),
+        weights,
+    ))
+}
+
+fn post_compare(ecosystems: &[Ecosystem], body: &str) -> Result<Body, Error> {
+    let req: CompareRequest = serde_json::from_str(body)
+        .map_err(|e| Error::bad_request(format!("invalid request body: {}", e)))?;
+
+    // Uploads get the same checks as the bundled data; replacing an entry
+    // may keep its id.
+    let mut errors = Vec::new();
+    for (i, custom) in req.ecosystems.iter().enumerate() {
+        if req.ecosystems[..i].iter().any(|e| e.id == custom.id) {
+            errors.push(format!("{}: uploaded more than once", custom.id));
+            continue;
+        }
+        let others: Vec<Ecosystem> = ecosystems
+            .iter()
+            .filter(|e| e.id != custom.id)
+            .cloned()
+            .collect();
+        errors.extend(
+            custom
+                .validate(&others)
+                .into_iter()
+                .map(|e| format!("{}: {}", custom.id, e)),
+        );
+    }
+    if !errors.is_empty() {
+        return Err(Error::bad_request(format!(
+            "invalid ecosystems: {}",
+            errors.join("; ")
+        )));
+    }
+
+    let mut merged = ecosystems.to_vec();
+    for custom in req.ecosystems {
+        match merged.iter_mut().find(|e| e.id == custom.id) {
+            Some(existing) => *existing = custom,
+            None => merged.push(custom),
+        }
+    }
+
+    let weights = match req.weights {
+        Some(w) if Dimension::ALL.iter().any(|&d| w.get(d).is_nan() || w.get(d) < 0.0) => {
+            return Err(Error::bad_request("weights must be non-negative numbers"));
+        }
+        Some(w) => w,
+        None => profile(req.profile.as_deref())?,
+    };
+    compare(
+        &merged,
+        &req.src,
+        &req.dst,
+        req.src_mode.as_deref(),
+        req.dst_mode.as_deref(),
+        weights,
+    )
+}
+
+fn route(ecosystems: &[Ecosystem], method: &Method, url: &str, body: &str) -> Result<Body, Error> {
+    let (path, params) = split_url(url);
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (method, &segments[..]) {
+        (Method::Get, ["ecosystems"]) => Body::json(&EcosystemList {
+            dataset_version: DATASET_VERSION,
+            ecosystems,
+        }),
+        (Method::Get, ["compare"]) => compare(
+            ecosystems,
+            required(&params, "src")?,
+            required(&params, "dst")?,
+            param(&params, "src_mode"),
+            param(&params, "dst_mode"),
+            profile(param(&params, "profile"))?,
+        ),
+        (Method::Post, ["compare"]) => post_compare(ecosystems, body),
+        (Method::Get, ["rank", src]) => {
+            let src = find(ecosystems, &percent_decode(src))?;
+            let src_mode = deploy_mode(src, param(&params, "src_mode"))?;
+            let weights = profile(param(&params, "profile"))?;
+            Body::json(&RankResponse::new(
+                src,
+                src_mode.as_deref(),
+                ecosystems,
+                weights,
+            ))
+        }
+        (Method::Get, ["openapi.yaml"]) => Ok(Body {
+            content_type: "application/yaml",
+            text: OPENAPI.to_string(),
+        }),
+        (_, ["ecosystems"] | ["compare"] | ["rank", _] | ["openapi.yaml"]) => Err(Error(
+            405,
+            format!("method {} not allowed on {}", method, path),
+        )),
+        _ => Err(Error::not_found(format!("no route for {}", path))),
+    }
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+fn handle(ecosystems: &[Ecosystem], mut request: Request) {
+    let mut body = String::new();
+    let read = request.as_reader().take(MAX_BODY).read_to_string(&mut body);
+
+    let result = match read {
+        Ok(_) => route(ecosystems, request.method(), request.url(), &body),
+        Err(e) => Err(Error::bad_request(format!(
+            "unreadable request body: {}",
+            e
+        ))),
+    };
+    let (status, body) = match result {
+        Ok(body) => (200, body),
+        Err(Error(status, msg)) => (
+            status,
+            Body::json(&ErrorResponse { error: &msg }).unwrap_or(Body {
+                content_type: "text/plain",
+                text: msg.clone(),
+            }),
+        ),
+    };
+
+    eprintln!("{} {} {}", request.method(), request.url(), status);
+    let response = Response::from_string(body.text)
+        .with_status_code(status)
+        .with_header(header("Content-Type", body.content_type))
+        .with_header(header("Access-Control-Allow-Origin", "*"));
+    if let Err(e) = request.respond(response) {
+        eprintln!("respond: {}", e);
+    }
+}
+
+/// Serve the API on `addr` until the process is killed.
+pub fn serve(addr: &str, ecosystems: &[Ecosystem]) -> Result<(), String> {
+    let server = Server::http(addr).map_err(|e| format!("listen on {}: {}", addr, e))?;
+    eprintln!("listening on http://{}", addr);
+    for request in server.incoming_requests() {
+        handle(ecosystems, request);
+    }
+    Ok(())
+}
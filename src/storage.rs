@@ -0,0 +1,37 @@
+//! Settings kept in `localStorage`. Failures are ignored: without storage
+//! the app still works, it just forgets between visits.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// `None` when the key is unset or storage is unavailable.
+pub fn load(key: &str) -> Option<String> {
+    storage()?.get_item(key).ok()?
+}
+
+pub fn save(key: &str, value: &str) {
+    if let Some(storage) = storage() {
+        let _ = storage.set_item(key, value);
+    }
+}
+
+pub fn remove(key: &str) {
+    if let Some(storage) = storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+/// `None` also when the stored JSON no longer parses.
+pub fn load_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    serde_json::from_str(&load(key)?).ok()
+}
+
+pub fn save_json<T: Serialize + ?Sized>(key: &str, value: &T) {
+    if let Ok(json) = serde_json::to_string(value) {
+        save(key, &json);
+    }
+}
@@ -1 +0,0 @@
-pub const CYAN: &str = "#1bb5ce";
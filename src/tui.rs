@@ -0,0 +1,466 @@
+//! Terminal UI (`bc-techmap tui`). Follows the web app's flow: pick a source,
+//! then a destination, Esc to start over.
+
+use std::io;
+
+use bc_techmap_core::data::ecosystem::{load_ecosystems, Ecosystem};
+use bc_techmap_core::data::findings::{Finding, Severity};
+use bc_techmap_core::data::scoring::{
+    compute_migration, difficulty_band, MigrationReport, DIFFICULTY_LABELS,
+};
+use bc_techmap_core::filter::fuzzy_score;
+use bc_techmap_core::text::{finding_text, severity_label};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+/// Band colors, following the ring view's palette but lifted for dark
+/// terminal backgrounds.
+const BAND_COLORS: [Color; 5] = [
+    Color::Rgb(0x92, 0xcc, 0xd6),
+    Color::Rgb(0x28, 0x97, 0xa3),
+    Color::Rgb(0x1b, 0xb5, 0xce),
+    Color::Rgb(0x4a, 0x6f, 0xa5),
+    Color::Rgb(0xc0, 0x3b, 0x30),
+];
+
+const HELP: &str =
+    "type to filter · ↑↓ move · Enter select · Tab ring/matrix · Esc back · Ctrl-C quit";
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Ring,
+    Matrix,
+}
+
+/// Mirrors the web app's `AppState`, with ecosystems by index.
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Idle,
+    SourceSelected { source: usize },
+    ShowResults { source: usize, dest: usize },
+}
+
+struct Tui {
+    ecosystems: Vec<Ecosystem>,
+    /// Overall score for every ordered pair, `None` on the diagonal.
+    matrix: Vec<Vec<Option<f64>>>,
+    view: View,
+    state: State,
+    query: String,
+    list: ListState,
+    report_scroll: u16,
+}
+
+impl Tui {
+    fn new(ecosystems: Vec<Ecosystem>) -> Self {
+        let matrix = ecosystems
+            .iter()
+            .map(|src| {
+                ecosystems
+                    .iter()
+                    .map(|dst| {
+                        (dst.id != src.id).then(|| compute_migration(src, dst, None, None).overall)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Tui {
+            ecosystems,
+            matrix,
+            view: View::Ring,
+            state: State::Idle,
+            query: String::new(),
+            list: ListState::default().with_selected(Some(0)),
+            report_scroll: 0,
+        }
+    }
+
+    fn source(&self) -> Option<usize> {
+        match self.state {
+            State::Idle => None,
+            State::SourceSelected { source } | State::ShowResults { source, .. } => Some(source),
+        }
+    }
+
+    fn dest(&self) -> Option<usize> {
+        match self.state {
+            State::ShowResults { dest, .. } => Some(dest),
+            _ => None,
+        }
+    }
+
+    /// Ecosystem indices matching the query, best match first. Once a source
+    /// is picked it's left out, since it can't be its own destination.
+    fn candidates(&self) -> Vec<usize> {
+        let mut scored: Vec<(i32, usize)> = self
+            .ecosystems
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != self.source())
+            .filter_map(|(i, e)| {
+                let score = fuzzy_score(&self.query, &e.name).max(fuzzy_score(&self.query, &e.id));
+                score.map(|s| (s, i))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, i)| i).collect()
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        let len = self.candidates().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.list.selected().unwrap_or(0) as isize;
+        let next = (current + delta).rem_euclid(len as isize) as usize;
+        self.list.select(Some(next));
+    }
+
+    fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.list.select(Some(0));
+    }
+
+    fn select(&mut self) {
+        let Some(&picked) = self.candidates().get(self.list.selected().unwrap_or(0)) else {
+            return;
+        };
+        self.state = match self.state {
+            State::Idle => State::SourceSelected { source: picked },
+            State::SourceSelected { source } | State::ShowResults { source, .. } => {
+                State::ShowResults {
+                    source,
+                    dest: picked,
+                }
+            }
+        };
+        self.report_scroll = 0;
+        self.set_query(String::new());
+    }
+
+    /// Handle a key press; returns false to quit.
+    fn on_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        match code {
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Esc if !self.query.is_empty() => self.set_query(String::new()),
+            KeyCode::Esc if self.state == State::Idle => return false,
+            KeyCode::Esc => {
+                self.state = State::Idle;
+                self.list.select(Some(0));
+            }
+            KeyCode::Tab => {
+                self.view = match self.view {
+                    View::Ring => View::Matrix,
+                    View::Matrix => View::Ring,
+                }
+            }
+            KeyCode::Up => self.move_cursor(-1),
+            KeyCode::Down => self.move_cursor(1),
+            KeyCode::PageUp => self.report_scroll = self.report_scroll.saturating_sub(5),
+            KeyCode::PageDown => self.report_scroll = self.report_scroll.saturating_add(5),
+            KeyCode::Enter => self.select(),
+            KeyCode::Backspace => {
+                let mut query = self.query.clone();
+                query.pop();
+                self.set_query(query);
+            }
+            KeyCode::Char(c) => {
+                let query = format!("{}{}", self.query, c);
+                self.set_query(query);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let report_width = if self.dest().is_some() { 58 } else { 0 };
+        let [list, view, report_area] = Layout::horizontal([
+            Constraint::Length(26),
+            Constraint::Fill(1),
+            Constraint::Length(report_width),
+        ])
+        .areas(main);
+
+        self.draw_list(frame, list);
+        match self.view {
+            View::Ring => self.draw_ring(frame, view),
+            View::Matrix => self.draw_matrix(frame, view),
+        }
+        if let State::ShowResults { source, dest } = self.state {
+            let report =
+                compute_migration(&self.ecosystems[source], &self.ecosystems[dest], None, None);
+            self.draw_report(frame, report, source, dest, report_area);
+        }
+        frame.render_widget(Paragraph::new(HELP).dark_gray(), help);
+    }
+
+    fn draw_list(&mut self, frame: &mut Frame, area: Rect) {
+        let title = match self.state {
+            State::Idle => " Source ",
+            _ => " Destination ",
+        };
+        let source = self.source();
+        let items: Vec<ListItem> = self
+            .candidates()
+            .into_iter()
+            .map(|i| {
+                let eco = &self.ecosystems[i];
+                let mut spans = vec![Span::raw(eco.name.clone())];
+                if let Some(score) = source.and_then(|s| self.matrix[s][i]) {
+                    spans.push(Span::styled(
+                        format!(" {:.0}", score * 100.0),
+                        Style::new().fg(BAND_COLORS[difficulty_band(score)]),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let block = Block::bordered()
+            .title(title)
+            .title_bottom(format!(" > {} ", self.query));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, area, &mut self.list);
+    }
+
+    /// Difficulty bands as nested boxes, Extreme outermost, with the source
+    /// in the middle.
+    fn draw_ring(&self, frame: &mut Frame, area: Rect) {
+        let Some(source) = self.source() else {
+            let prompt = Paragraph::new("Pick an ecosystem to center it in the ring view")
+                .alignment(Alignment::Center)
+                .block(Block::bordered().title(" Ring "));
+            frame.render_widget(prompt, area);
+            return;
+        };
+
+        let mut bands: [Vec<(usize, f64)>; 5] = Default::default();
+        for (i, score) in self.matrix[source].iter().enumerate() {
+            if let Some(score) = score {
+                bands[difficulty_band(*score)].push((i, *score));
+            }
+        }
+
+        let mut area = area;
+        for band in (0..bands.len()).rev() {
+            let color = BAND_COLORS[band];
+            let block = Block::bordered()
+                .title(format!(" {} ", DIFFICULTY_LABELS[band]))
+                .border_style(Style::new().fg(color));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let members: Vec<Span> = bands[band]
+                .iter()
+                .flat_map(|&(i, score)| {
+                    let mut style = Style::new().fg(color);
+                    if Some(i) == self.dest() {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    [
+                        Span::styled(
+                            format!("{} {:.0}", self.ecosystems[i].name, score * 100.0),
+                            style,
+                        ),
+                        Span::raw("  "),
+                    ]
+                })
+                .collect();
+            let width: usize = members.iter().map(|s| s.width()).sum();
+            let rows = (width.div_ceil(inner.width.max(1) as usize) as u16).clamp(1, 3);
+            let text = Paragraph::new(Line::from(members)).wrap(Wrap { trim: true });
+            frame.render_widget(
+                text,
+                Rect {
+                    height: rows.min(inner.height),
+                    ..inner
+                },
+            );
+
+            area = Rect {
+                x: inner.x + 1,
+                y: inner.y + rows,
+                width: inner.width.saturating_sub(2),
+                height: inner.height.saturating_sub(rows),
+            };
+            if area.height < 3 {
+                break;
+            }
+        }
+
+        let center = Paragraph::new(self.ecosystems[source].name.clone().bold())
+            .alignment(Alignment::Center);
+        let middle = Rect {
+            y: area.y + area.height / 2,
+            height: 1.min(area.height),
+            ..area
+        };
+        frame.render_widget(center, middle);
+    }
+
+    /// Every pair as a colored grid, sources down, destinations across.
+    fn draw_matrix(&self, frame: &mut Frame, area: Rect) {
+        let label = |e: &Ecosystem| format!("{:<8.8}", e.short);
+        let mut lines = vec![Line::from(
+            std::iter::once(Span::raw(" ".repeat(9)))
+                .chain(self.ecosystems.iter().enumerate().map(|(j, e)| {
+                    let style = if Some(j) == self.dest() {
+                        Style::new().bold()
+                    } else {
+                        Style::new()
+                    };
+                    Span::styled(format!("{:^4.4}", e.short), style)
+                }))
+                .collect::<Vec<_>>(),
+        )];
+
+        for (i, src) in self.ecosystems.iter().enumerate() {
+            let mut row_style = Style::new();
+            if Some(i) == self.source() {
+                row_style = row_style.bold();
+            }
+            let mut spans = vec![Span::styled(format!("{} ", label(src)), row_style)];
+            for (j, score) in self.matrix[i].iter().enumerate() {
+                spans.push(match score {
+                    Some(score) => {
+                        let mut style = Style::new()
+                            .fg(Color::Black)
+                            .bg(BAND_COLORS[difficulty_band(*score)]);
+                        if Some(i) == self.source() && Some(j) == self.dest() {
+                            style = style.add_modifier(Modifier::REVERSED);
+                        }
+                        Span::styled(format!("{:^4.0}", score * 100.0), style)
+                    }
+                    None => Span::raw(" ·  "),
+                });
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let legend: Vec<Span> = DIFFICULTY_LABELS
+            .iter()
+            .zip(BAND_COLORS)
+            .flat_map(|(label, color)| {
+                [
+                    Span::styled(
+                        format!(" {} ", label),
+                        Style::new().fg(Color::Black).bg(color),
+                    ),
+                    Span::raw(" "),
+                ]
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(Line::from(legend));
+
+        let matrix = Paragraph::new(lines).block(Block::bordered().title(" Matrix "));
+        frame.render_widget(matrix, area);
+    }
+
+    fn draw_report(
+        &self,
+        frame: &mut Frame,
+        report: MigrationReport,
+        source: usize,
+        dest: usize,
+        area: Rect,
+    ) {
+        let band_color = BAND_COLORS[difficulty_band(report.overall)];
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw("Overall  "),
+                Span::styled(
+                    format!(
+                        "{:.0}% · {}",
+                        report.overall * 100.0,
+                        report.difficulty_label
+                    ),
+                    Style::new().fg(band_color).bold(),
+                ),
+            ]),
+            Line::default(),
+        ];
+
+        for d in &report.dimensions {
+            let filled = (d.score * 12.0).round() as usize;
+            let color = BAND_COLORS[difficulty_band(d.score)];
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:<18}", d.name)),
+                Span::styled("█".repeat(filled), Style::new().fg(color)),
+                Span::styled("░".repeat(12 - filled), Style::new().dark_gray()),
+                Span::raw(format!(" {:>3.0}% ", d.score * 100.0)),
+                Span::styled(d.label.clone(), Style::new().fg(color)),
+                Span::raw(if d.overridden { " *" } else { "" }),
+            ]));
+        }
+
+        if let Some(ov) = report.pair_override {
+            lines.push(Line::default());
+            lines.push(Line::from(format!("* {}", ov.justification)).italic());
+        }
+
+        let findings = |title: &'static str, items: &[Finding], lines: &mut Vec<Line>| {
+            if items.is_empty() {
+                return;
+            }
+            lines.push(Line::default());
+            lines.push(Line::from(title).bold());
+            for f in items {
+                let severity = match f.severity {
+                    Severity::Critical | Severity::Major => Style::new().red(),
+                    Severity::Minor => Style::new().yellow(),
+                    Severity::Info => Style::new().dark_gray(),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<8} ", severity_label(f.severity)), severity),
+                    Span::raw(finding_text(f)),
+                ]));
+                for m in &f.mitigations {
+                    lines.push(Line::from(format!("  → {}", m.text)).dark_gray());
+                }
+            }
+        };
+        findings("Challenges", &report.challenges, &mut lines);
+        findings("Positives", &report.positives, &mut lines);
+
+        let title = format!(
+            " {} → {} ",
+            self.ecosystems[source].name, self.ecosystems[dest].name
+        );
+        let paragraph = Paragraph::new(lines)
+            .block(Block::bordered().title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.report_scroll, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+fn run_loop(terminal: &mut DefaultTerminal, tui: &mut Tui) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| tui.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !tui.on_key(key.code, key.modifiers) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Run the terminal UI until the user quits.
+pub fn run() -> Result<(), String> {
+    let mut tui = Tui::new(load_ecosystems());
+    let mut terminal = ratatui::init();
+    let result = run_loop(&mut terminal, &mut tui);
+    ratatui::restore();
+    result.map_err(|e| format!("terminal: {}", e))
+}